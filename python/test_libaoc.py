@@ -0,0 +1,67 @@
+import os
+import unittest
+
+import libaoc
+
+DATA = os.path.join(os.path.dirname(__file__), "..", "data", "2022")
+
+
+def example(day, name="example"):
+    with open(os.path.join(DATA, f"day{day:02}.{name}")) as f:
+        return f.read()
+
+
+class Solve(unittest.TestCase):
+    def test_solve(self):
+        self.assertEqual(libaoc.solve(1, example(1), 1), 24000)
+        self.assertEqual(libaoc.solve(12, example(12), 2, year=2022), 29)
+
+    def test_day_modules(self):
+        self.assertEqual(libaoc.y2022.day12.solve(example(12), 1), 31)
+        self.assertEqual(libaoc.y2022.day21.solve(example(21), 1), 152)
+
+    def test_days(self):
+        self.assertIn((2022, 25), libaoc.days())
+
+    def test_errors(self):
+        with self.assertRaises(KeyError):
+            libaoc.solve(26, "", 1)
+        with self.assertRaises(ValueError):
+            libaoc.solve(1, example(1), 3)
+
+
+class Grid(unittest.TestCase):
+    def test_grid(self):
+        grid = libaoc.Grid("S.#\n..E\n")
+        self.assertEqual((grid.height, grid.width), (2, 3))
+        self.assertEqual(grid[(1, 2)], "E")
+        self.assertEqual(grid.find("S"), (0, 0))
+        self.assertEqual(sorted(grid.neighbours4((0, 0))), [(0, 1), (1, 0)])
+        self.assertEqual(len(grid.neighbours8((1, 1))), 5)
+        self.assertEqual(grid.distances((0, 0))[(1, 2)], 3)
+        grid[(0, 2)] = "."
+        self.assertEqual(str(grid), "S..\n..E\n")
+        with self.assertRaises(IndexError):
+            grid[(2, 0)]
+
+
+class Intervals(unittest.TestCase):
+    def test_intervals(self):
+        self.assertEqual(libaoc.merge_intervals([(5, 8), (0, 2), (3, 4), (10, 9)]), [(0, 8)])
+        self.assertEqual(libaoc.covered([(0, 2), (1, 5), (7, 7)]), 7)
+        self.assertEqual(libaoc.first_gap([(0, 2), (4, 9)], (0, 9)), 3)
+        self.assertIsNone(libaoc.first_gap([(0, 9)], (0, 9)))
+
+
+class Search(unittest.TestCase):
+    def test_bfs(self):
+        self.assertEqual(libaoc.bfs([1], lambda n: [n + 1, n * 2], lambda n: n == 10), (10, 4))
+        self.assertIsNone(libaoc.bfs([0], lambda n: [n + 1] if n < 3 else [], lambda n: n == 10))
+
+    def test_callback_errors(self):
+        with self.assertRaises(ZeroDivisionError):
+            libaoc.bfs([1], lambda n: [1 // 0], lambda n: False)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,121 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+
+use crate::ledger::Ledger;
+use crate::lint::{self, Finding};
+use crate::registry::Day;
+use crate::runner::{self, Answer, Record, RunOptions, Status};
+
+// everyone's inputs for a day, `<name>.input` files next to an `answers` ledger keyed by name
+pub fn batch_dir(year: u32, day: u32) -> PathBuf {
+    Path::new(&runner::data_dir(year)).join(format!("day{:02}", day))
+}
+
+pub fn ledger_path(dir: &Path) -> PathBuf {
+    dir.join("answers")
+}
+
+// names of the inputs in dir, sorted
+pub fn batch_inputs(dir: &Path) -> Result<Vec<String>> {
+    let mut inputs: Vec<String> = std::fs::read_dir(dir)
+        .map_err(|e| anyhow!("{}: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| name.strip_suffix(".input").map(str::to_string))
+        .collect();
+    inputs.sort();
+    Ok(inputs)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Matches,
+    // the answer the ledger has instead
+    Differs(Answer),
+    // the ledger has no answer for this input and part
+    Unrecorded,
+    // the part did not produce an answer
+    Failed,
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub input: String,
+    pub parts: Vec<(Record, Verdict)>,
+    // the day's assumptions checked against this input
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    pub fn failures(&self) -> usize {
+        self.parts.iter().filter(|(_, verdict)| matches!(verdict, Verdict::Differs(_) | Verdict::Failed)).count()
+    }
+
+    pub fn broken(&self) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(|finding| finding.broken.is_some())
+    }
+
+    fn print(&self, day: &Day) {
+        for (record, verdict) in &self.parts {
+            let label = format!("{} day{:02} part {} [{}]", day.year, day.day, record.part, self.input);
+            let verdict = match verdict {
+                Verdict::Matches => "ok".to_string(),
+                Verdict::Differs(expected) => format!("wrong, ledger expects {}", expected.to_line()),
+                Verdict::Unrecorded => "not in the ledger".to_string(),
+                Verdict::Failed => "failed".to_string(),
+            };
+            println!("{} {} ({:.3} ms) {}", label, record.result(), record.time_ms, verdict);
+        }
+        for finding in self.broken() {
+            println!("{} day{:02} [{}] assumption {} broken, {}", day.year, day.day, self.input, finding.assumption, finding.broken.as_deref().unwrap_or_default());
+        }
+    }
+}
+
+// solves both parts of one named input in dir and checks them against the ledger
+pub fn verify_input(day: &Day, dir: &Path, input_name: &str, ledger: &Ledger, options: &RunOptions) -> Result<Report> {
+    let path = dir.join(format!("{}.input", input_name));
+    let input = std::fs::read_to_string(&path).map_err(|e| anyhow!("{}: {}", path.display(), e))?;
+    let parts = (1..=2)
+        .map(|part| {
+            let record = runner::solve(day, part, input_name, &input, options);
+            let verdict = match (record.status, &record.answer, ledger.get(input_name, part)) {
+                (Status::Ok, Some(answer), Some(expected)) if answer == expected => Verdict::Matches,
+                (Status::Ok, _, Some(expected)) => Verdict::Differs(expected.clone()),
+                (Status::Ok, _, None) => Verdict::Unrecorded,
+                _ => Verdict::Failed,
+            };
+            (record, verdict)
+        })
+        .collect();
+    Ok(Report { input: input_name.to_string(), parts, findings: lint::check(day, &input) })
+}
+
+// runs every day over every input in its batch directory, or in dir when given
+pub fn batch(days: &[&Day], dir: Option<&Path>, options: &RunOptions) -> Result<()> {
+    let (mut failures, mut broken) = (0, 0);
+    'days: for day in days {
+        let dir = dir.map_or_else(|| batch_dir(day.year, day.day), Path::to_path_buf);
+        let ledger = Ledger::read(&ledger_path(&dir))?;
+        let inputs = match dir.is_dir() {
+            true => batch_inputs(&dir)?,
+            false => Vec::new(),
+        };
+        if inputs.is_empty() {
+            println!("{} day{:02} has no inputs in {}", day.year, day.day, dir.display());
+        }
+        for input_name in inputs {
+            let report = verify_input(day, &dir, &input_name, &ledger, options)?;
+            report.print(day);
+            failures += report.failures();
+            broken += report.broken().count();
+            if options.progress.cancelled() {
+                break 'days;
+            }
+        }
+    }
+    match (failures, broken) {
+        (0, 0) => Ok(()),
+        (failures, broken) => Err(anyhow!("{} part(s) did not match the ledger, {} assumption(s) broken", failures, broken)),
+    }
+}
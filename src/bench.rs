@@ -0,0 +1,119 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::alloc::{self, AllocStats};
+use crate::registry::Day;
+use crate::runner::{self, Format, Status};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BenchRecord {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub iterations: usize,
+    pub min_ms: f64,
+    pub median_ms: f64,
+    pub mean_ms: f64,
+    // allocations of the first iteration, only with the counting allocator installed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alloc: Option<AllocStats>,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl BenchRecord {
+    fn failed(day: &Day, part: u32, input: &str, status: Status, error: String) -> Self {
+        BenchRecord { year: day.year, day: day.day, part, input: input.to_string(), iterations: 0, min_ms: 0.0, median_ms: 0.0, mean_ms: 0.0, alloc: None, status, error: Some(error) }
+    }
+
+    pub fn print(&self, format: Format) {
+        match format {
+            Format::Json => println!("{}", serde_json::to_string(self).unwrap()),
+            Format::Text if self.status != Status::Ok => {
+                println!("{} day{:02} part {} [{}] {:?}: {}", self.year, self.day, self.part, self.input, self.status, self.error.as_deref().unwrap_or_default())
+            }
+            Format::Text => {
+                let alloc = match self.alloc {
+                    Some(stats) => format!(
+                        ", {} allocs, {} allocated, {} peak",
+                        stats.allocations,
+                        alloc::format_bytes(stats.bytes),
+                        alloc::format_bytes(stats.peak_bytes)
+                    ),
+                    None => String::new(),
+                };
+                println!(
+                    "{} day{:02} part {} [{}] min {:.3} ms, median {:.3} ms, mean {:.3} ms over {}{}",
+                    self.year, self.day, self.part, self.input, self.min_ms, self.median_ms, self.mean_ms, self.iterations, alloc
+                );
+            }
+        }
+    }
+}
+
+fn round_ms(start: Instant) -> f64 {
+    (start.elapsed().as_secs_f64() * 1e6).round() / 1e3
+}
+
+pub fn bench_part(day: &Day, part: u32, input_name: &str, iterations: usize) -> BenchRecord {
+    let Some(solver) = day.part(part) else {
+        return BenchRecord::failed(day, part, input_name, Status::Error, format!("no part {}", part));
+    };
+    let input = match std::fs::read_to_string(runner::input_path(day.year, day.day, input_name)) {
+        Ok(input) => input,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return BenchRecord::failed(day, part, input_name, Status::MissingInput, e.to_string())
+        }
+        Err(e) => return BenchRecord::failed(day, part, input_name, Status::Error, e.to_string()),
+    };
+    let _span = tracing::info_span!("bench", year = day.year, day = day.day, part, input = input_name).entered();
+    let mut times = Vec::with_capacity(iterations);
+    let mut stats = None;
+    for iteration in 0..iterations.max(1) {
+        let start = Instant::now();
+        let (result, alloc) = alloc::measure(|| panic::catch_unwind(AssertUnwindSafe(|| solver(&input))));
+        times.push(round_ms(start));
+        match result {
+            Ok(Ok(answer)) => tracing::debug!(iteration, answer = %answer.to_line(), "iteration"),
+            Ok(Err(e)) => return BenchRecord::failed(day, part, input_name, Status::Error, format!("{:#}", e)),
+            Err(payload) => return BenchRecord::failed(day, part, input_name, Status::Panic, runner::panic_message(payload.as_ref())),
+        }
+        stats = stats.or(alloc);
+    }
+    times.sort_by(f64::total_cmp);
+    BenchRecord {
+        year: day.year,
+        day: day.day,
+        part,
+        input: input_name.to_string(),
+        iterations: times.len(),
+        min_ms: times[0],
+        median_ms: times[times.len() / 2],
+        mean_ms: ((times.iter().sum::<f64>() / times.len() as f64) * 1e3).round() / 1e3,
+        alloc: stats,
+        status: Status::Ok,
+        error: None,
+    }
+}
+
+pub fn bench_days(days: &[&Day], input_name: &str, iterations: usize, format: Format) -> Result<()> {
+    let mut failures = 0;
+    for day in days {
+        for part in 1..=2 {
+            let record = bench_part(day, part, input_name, iterations);
+            if record.status != Status::Ok {
+                failures += 1;
+            }
+            record.print(format);
+        }
+    }
+    match failures {
+        0 => Ok(()),
+        n => Err(anyhow!("{} part(s) did not produce an answer", n)),
+    }
+}
@@ -1,15 +0,0 @@
-use anyhow::Result;
-
-fn part01() -> Result<u32> {
-    Ok(0)
-}
-
-fn part02() -> Result<u32> {
-    Ok(0)
-}
-
-fn main() -> Result<()> {
-    println!("{:?}", part01().unwrap());
-    println!("{:?}", part02().unwrap());
-    Ok(())
-}
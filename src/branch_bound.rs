@@ -0,0 +1,207 @@
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use anyhow::Result;
+
+use crate::progress;
+
+// states between cancellation checks
+const CHECK_EVERY: u64 = 4096;
+
+// a maximization problem, states are partial solutions that can be extended
+pub trait Problem {
+    type State: Clone;
+    // states with the same key can dominate each other, `()` without dominance pruning
+    type Key: Hash + Eq;
+
+    fn successors(&self, state: &Self::State, next: &mut Vec<Self::State>);
+
+    // what the state is worth if it is not extended any further
+    fn objective(&self, state: &Self::State) -> u64;
+
+    // no extension of the state is worth more than this
+    fn upper_bound(&self, state: &Self::State) -> u64;
+
+    fn dominance_key(&self, _state: &Self::State) -> Option<Self::Key> {
+        None
+    }
+
+    // a is at least as good as b in every way that matters, only asked for states with equal keys
+    fn dominates(&self, _a: &Self::State, _b: &Self::State) -> bool {
+        false
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strategy {
+    // little memory and finds good incumbents early when successors come best first
+    #[default]
+    DepthFirst,
+    // expands the state with the highest bound first, never expands a state it could have pruned
+    BestFirst,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Stats {
+    pub expanded: u64,
+    pub pruned_by_bound: u64,
+    pub pruned_by_dominance: u64,
+    pub improvements: u64,
+}
+
+impl Stats {
+    // logs the search effort, visible with -vv
+    pub fn report(&self, name: &str) {
+        tracing::debug!(
+            search = name,
+            expanded = self.expanded,
+            pruned_by_bound = self.pruned_by_bound,
+            pruned_by_dominance = self.pruned_by_dominance,
+            improvements = self.improvements,
+            "branch and bound"
+        );
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Solution<S> {
+    pub value: u64,
+    // the state that reached the value, the start when nothing beat it
+    pub state: S,
+    pub stats: Stats,
+}
+
+struct Queued<S> {
+    bound: u64,
+    state: S,
+}
+
+impl<S> PartialEq for Queued<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bound == other.bound
+    }
+}
+
+impl<S> Eq for Queued<S> {}
+
+impl<S> PartialOrd for Queued<S> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S> Ord for Queued<S> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.bound.cmp(&other.bound)
+    }
+}
+
+enum Frontier<S> {
+    Stack(Vec<S>),
+    Heap(BinaryHeap<Queued<S>>),
+}
+
+impl<S> Frontier<S> {
+    fn push(&mut self, state: S, bound: u64) {
+        match self {
+            Frontier::Stack(stack) => stack.push(state),
+            Frontier::Heap(heap) => heap.push(Queued { bound, state }),
+        }
+    }
+
+    fn pop(&mut self) -> Option<S> {
+        match self {
+            Frontier::Stack(stack) => stack.pop(),
+            Frontier::Heap(heap) => heap.pop().map(|queued| queued.state),
+        }
+    }
+}
+
+// the best value reachable from start, with the incumbent tracked and dominated states dropped
+pub fn maximize<P: Problem>(problem: &P, start: P::State, strategy: Strategy) -> Result<Solution<P::State>> {
+    let mut stats = Stats::default();
+    let mut best = Solution { value: problem.objective(&start), state: start.clone(), stats };
+    let mut frontier = match strategy {
+        Strategy::DepthFirst => Frontier::Stack(Vec::new()),
+        Strategy::BestFirst => Frontier::Heap(BinaryHeap::new()),
+    };
+    frontier.push(start.clone(), problem.upper_bound(&start));
+    // the non-dominated states seen so far for every key
+    let mut seen: HashMap<P::Key, Vec<P::State>> = HashMap::new();
+    let mut next = Vec::new();
+    while let Some(state) = frontier.pop() {
+        // the incumbent may have improved since the state was queued
+        if problem.upper_bound(&state) <= best.value {
+            stats.pruned_by_bound += 1;
+            continue;
+        }
+        if stats.expanded.is_multiple_of(CHECK_EVERY) {
+            progress::check()?;
+        }
+        stats.expanded += 1;
+        problem.successors(&state, &mut next);
+        // pushed in reverse so a stack expands them in the order they were generated
+        for successor in next.drain(..).rev() {
+            let value = problem.objective(&successor);
+            if value > best.value {
+                best.value = value;
+                best.state = successor.clone();
+                stats.improvements += 1;
+            }
+            let bound = problem.upper_bound(&successor);
+            if bound <= best.value {
+                stats.pruned_by_bound += 1;
+                continue;
+            }
+            if let Some(key) = problem.dominance_key(&successor) {
+                let states = seen.entry(key).or_default();
+                if states.iter().any(|other| problem.dominates(other, &successor)) {
+                    stats.pruned_by_dominance += 1;
+                    continue;
+                }
+                states.retain(|other| !problem.dominates(&successor, other));
+                states.push(successor.clone());
+            }
+            frontier.push(successor, bound);
+        }
+    }
+    best.stats = stats;
+    Ok(best)
+}
+
+// the problem with every state carrying the states it was reached through
+struct Traced<'a, P>(&'a P);
+
+impl<P: Problem> Problem for Traced<'_, P> {
+    type State = (P::State, Vec<P::State>);
+    type Key = P::Key;
+
+    fn successors(&self, (state, path): &Self::State, next: &mut Vec<Self::State>) {
+        let mut successors = Vec::new();
+        self.0.successors(state, &mut successors);
+        next.extend(successors.into_iter().map(|successor| (successor, [path.as_slice(), std::slice::from_ref(state)].concat())));
+    }
+
+    fn objective(&self, (state, _): &Self::State) -> u64 {
+        self.0.objective(state)
+    }
+
+    fn upper_bound(&self, (state, _): &Self::State) -> u64 {
+        self.0.upper_bound(state)
+    }
+
+    fn dominance_key(&self, (state, _): &Self::State) -> Option<Self::Key> {
+        self.0.dominance_key(state)
+    }
+
+    fn dominates(&self, (a, _): &Self::State, (b, _): &Self::State) -> bool {
+        self.0.dominates(a, b)
+    }
+}
+
+// the same search as maximize, the solution is every state from start to the best one
+pub fn maximize_traced<P: Problem>(problem: &P, start: P::State, strategy: Strategy) -> Result<Solution<Vec<P::State>>> {
+    let Solution { value, state: (best, mut path), stats } = maximize(&Traced(problem), (start, Vec::new()), strategy)?;
+    path.push(best);
+    Ok(Solution { value, state: path, stats })
+}
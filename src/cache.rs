@@ -0,0 +1,117 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use crate::registry::Day;
+use crate::runner::Record;
+
+// FNV-1a, so keys stay stable across toolchains unlike std's DefaultHasher
+fn fnv1a(bytes: &[u8], mut hash: u64) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+pub fn input_hash(input: &str) -> u64 {
+    fnv1a(input.as_bytes(), FNV_OFFSET)
+}
+
+macro_rules! sources {
+    ($($file:literal),* $(,)?) => {
+        &[$(($file, include_str!($file))),*]
+    };
+}
+
+// every library module by file name, an answer can change with any of them as well as with the day,
+// tests/cache.rs fails when a module in src is missing
+pub const SHARED_SOURCES: &[(&str, &str)] = sources!(
+    "alloc.rs",
+    "batch.rs",
+    "bench.rs",
+    "branch_bound.rs",
+    "cache.rs",
+    "checked.rs",
+    "checkpoint.rs",
+    "context.rs",
+    "differential.rs",
+    "dp.rs",
+    "examples.rs",
+    "explain.rs",
+    "grid.rs",
+    "input.rs",
+    "interval.rs",
+    "ledger.rs",
+    "lib.rs",
+    "lint.rs",
+    "parallel.rs",
+    "progress.rs",
+    "python.rs",
+    "registry.rs",
+    "repl.rs",
+    "runner.rs",
+    "scaffold.rs",
+    "search.rs",
+    "server.rs",
+    "simulation.rs",
+    "snapshot.rs",
+    "template.rs",
+    "tui.rs",
+    "watch.rs",
+);
+
+// the enabled features that can change an answer, with checked-arith an answer that wrapped becomes an error
+pub fn answer_features() -> Vec<&'static str> {
+    let features = [("checked-arith", cfg!(feature = "checked-arith")), ("parallel", cfg!(feature = "parallel"))];
+    features.into_iter().filter_map(|(name, enabled)| enabled.then_some(name)).collect()
+}
+
+pub fn shared_hash(shared: &[(&str, &str)], features: &[&str]) -> u64 {
+    let hash = shared.iter().fold(fnv1a(env!("CARGO_PKG_VERSION").as_bytes(), FNV_OFFSET), |hash, (_, source)| fnv1a(source.as_bytes(), hash));
+    // every feature ends in a newline so the list cannot be split differently into the same bytes
+    features.iter().fold(fnv1a(b"\n", hash), |hash, feature| fnv1a(format!("{}\n", feature).as_bytes(), hash))
+}
+
+pub fn fingerprint(day: &Day, shared_hash: u64) -> u64 {
+    fnv1a(day.source.as_bytes(), shared_hash)
+}
+
+pub struct Cache {
+    dir: PathBuf,
+    shared_hash: u64,
+}
+
+impl Cache {
+    pub fn open() -> Result<Self> {
+        Self::with(PathBuf::from("./target/aoc-cache"), SHARED_SOURCES, &answer_features())
+    }
+
+    // a cache in dir keyed by the given shared sources and features instead of the compiled ones
+    pub fn with(dir: PathBuf, shared: &[(&str, &str)], features: &[&str]) -> Result<Self> {
+        std::fs::create_dir_all(&dir)?;
+        Ok(Cache { dir, shared_hash: shared_hash(shared, features) })
+    }
+
+    fn path(&self, day: &Day, part: u32, input: &str) -> PathBuf {
+        self.dir.join(format!(
+            "{}-day{:02}-{}-{:016x}-{:016x}.json",
+            day.year,
+            day.day,
+            part,
+            fingerprint(day, self.shared_hash),
+            input_hash(input)
+        ))
+    }
+
+    pub fn get(&self, day: &Day, part: u32, input: &str) -> Option<Record> {
+        let content = std::fs::read_to_string(self.path(day, part, input)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    pub fn put(&self, day: &Day, part: u32, input: &str, record: &Record) -> Result<()> {
+        Ok(std::fs::write(self.path(day, part, input), serde_json::to_string(record)?)?)
+    }
+}
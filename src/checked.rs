@@ -0,0 +1,117 @@
+use std::fmt::{self, Display};
+
+// integer arithmetic for the solvers that can overflow, with the `checked-arith` feature every
+// operation reports the operands that overflowed, without it they are the plain operators
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    message: String,
+}
+
+impl Overflow {
+    fn new(lhs: impl Display, op: &str, rhs: impl Display, ty: &str) -> Self {
+        Overflow { message: format!("{} {} {} overflows {}", lhs, op, rhs, ty) }
+    }
+
+    // names what was being computed, e.g. the monkey whose number overflowed
+    pub fn at(self, what: impl Display) -> Self {
+        Overflow { message: format!("{}: {}", what, self.message) }
+    }
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+pub trait Int: Copy + Display {
+    const NAME: &'static str;
+    fn is_zero(self) -> bool;
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    fn plain_add(self, rhs: Self) -> Self;
+    fn plain_sub(self, rhs: Self) -> Self;
+    fn plain_mul(self, rhs: Self) -> Self;
+    fn plain_div(self, rhs: Self) -> Self;
+    fn plain_pow(self, exp: u32) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {$(
+        impl Int for $t {
+            const NAME: &'static str = stringify!($t);
+            fn is_zero(self) -> bool { self == 0 }
+            fn checked_add(self, rhs: Self) -> Option<Self> { <$t>::checked_add(self, rhs) }
+            fn checked_sub(self, rhs: Self) -> Option<Self> { <$t>::checked_sub(self, rhs) }
+            fn checked_mul(self, rhs: Self) -> Option<Self> { <$t>::checked_mul(self, rhs) }
+            fn checked_div(self, rhs: Self) -> Option<Self> { <$t>::checked_div(self, rhs) }
+            fn checked_pow(self, exp: u32) -> Option<Self> { <$t>::checked_pow(self, exp) }
+            fn plain_add(self, rhs: Self) -> Self { self + rhs }
+            fn plain_sub(self, rhs: Self) -> Self { self - rhs }
+            fn plain_mul(self, rhs: Self) -> Self { self * rhs }
+            fn plain_div(self, rhs: Self) -> Self { self / rhs }
+            fn plain_pow(self, exp: u32) -> Self { self.pow(exp) }
+        }
+    )*};
+}
+
+impl_int!(i32, i64, u32, u64, usize);
+
+fn apply<T: Int>(lhs: T, op: &str, rhs: impl Display, checked: Option<T>, plain: impl FnOnce() -> T) -> Result<T, Overflow> {
+    if cfg!(feature = "checked-arith") {
+        checked.ok_or_else(|| Overflow::new(lhs, op, rhs, T::NAME))
+    } else {
+        Ok(plain())
+    }
+}
+
+pub fn add<T: Int>(lhs: T, rhs: T) -> Result<T, Overflow> {
+    apply(lhs, "+", rhs, lhs.checked_add(rhs), || lhs.plain_add(rhs))
+}
+
+pub fn sub<T: Int>(lhs: T, rhs: T) -> Result<T, Overflow> {
+    apply(lhs, "-", rhs, lhs.checked_sub(rhs), || lhs.plain_sub(rhs))
+}
+
+pub fn mul<T: Int>(lhs: T, rhs: T) -> Result<T, Overflow> {
+    apply(lhs, "*", rhs, lhs.checked_mul(rhs), || lhs.plain_mul(rhs))
+}
+
+// dividing by zero is reported as such instead of panicking, MIN / -1 is the one overflow
+pub fn div<T: Int>(lhs: T, rhs: T) -> Result<T, Overflow> {
+    if cfg!(feature = "checked-arith") && rhs.is_zero() {
+        return Err(Overflow { message: format!("{} / {} divides by zero", lhs, rhs) });
+    }
+    apply(lhs, "/", rhs, lhs.checked_div(rhs), || lhs.plain_div(rhs))
+}
+
+pub fn pow<T: Int>(base: T, exp: u32) -> Result<T, Overflow> {
+    apply(base, "^", exp, base.checked_pow(exp), || base.plain_pow(exp))
+}
+
+// `as` between the integer types the solvers mix, checked that the value fits
+pub trait Cast<U>: Sized {
+    fn cast(self) -> Result<U, Overflow>;
+}
+
+macro_rules! impl_cast {
+    ($($from:ty => $($to:ty),*);*) => {$($(
+        impl Cast<$to> for $from {
+            fn cast(self) -> Result<$to, Overflow> {
+                if cfg!(feature = "checked-arith") {
+                    <$to>::try_from(self).map_err(|_| Overflow { message: format!("{} does not fit in {}", self, stringify!($to)) })
+                } else {
+                    Ok(self as $to)
+                }
+            }
+        }
+    )*)*};
+}
+
+impl_cast!(u32 => i32, u64, usize; i32 => u32, i64, u64; i64 => i32, u64, u32; u64 => u32, i64; usize => u32, i32);
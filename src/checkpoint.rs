@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::context::{self, CheckpointOptions, Context};
+
+// one snapshot per year, day, part and input, later saves overwrite earlier ones
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Snapshot<T> {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub step: u64,
+    pub state: T,
+}
+
+pub fn path(context: &Context, options: &CheckpointOptions) -> PathBuf {
+    options.dir.join(format!("{}-day{:02}-{}-{:016x}.json", context.year, context.day, context.part, context.input_hash))
+}
+
+pub fn load<T: DeserializeOwned>(path: &Path) -> Result<Snapshot<T>> {
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+// whether the current part saves or resumes checkpoints at all
+pub fn enabled() -> bool {
+    context::with(|context| context.and_then(|context| context.checkpoints.as_ref()).is_some_and(|options| options.resume || options.every.is_some()))
+}
+
+// the state saved by an earlier run of the current part, if resuming was requested
+pub fn resume<T: DeserializeOwned>() -> Result<Option<T>> {
+    let path = context::with(|context| match context {
+        Some(context @ Context { checkpoints: Some(options), .. }) if options.resume => Some(path(context, options)),
+        _ => None,
+    });
+    match path {
+        Some(path) if path.exists() => {
+            let snapshot = load::<T>(&path)?;
+            tracing::info!(step = snapshot.step, path = %path.display(), "resuming from checkpoint");
+            Ok(Some(snapshot.state))
+        }
+        _ => Ok(None),
+    }
+}
+
+// saves the state returned by `state` when step is a multiple of the checkpoint interval
+pub fn save<T: Serialize>(step: u64, state: impl FnOnce() -> T) -> Result<()> {
+    let snapshot = context::with(|context| {
+        let context = context?;
+        let options = context.checkpoints.as_ref()?;
+        let every = options.every?;
+        (every > 0 && step > 0 && step.is_multiple_of(every)).then(|| (path(context, options), context.year, context.day, context.part))
+    });
+    let Some((path, year, day, part)) = snapshot else {
+        return Ok(());
+    };
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    // write and rename so an interrupted save never leaves a truncated snapshot behind
+    let tmp = path.with_extension("tmp");
+    std::fs::write(&tmp, serde_json::to_string(&Snapshot { year, day, part, step, state: state() })?)?;
+    std::fs::rename(&tmp, &path)?;
+    tracing::debug!(step, path = %path.display(), "checkpoint saved");
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use std::cell::RefCell;
+use std::path::PathBuf;
+
+use crate::explain::Explanation;
+use crate::progress::Progress;
+
+// what the runner knows about the part being solved, solvers reach it through `with`
+// instead of an extra argument so they stay plain `fn(&str)`
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub input_hash: u64,
+    pub checkpoints: Option<CheckpointOptions>,
+    pub progress: Option<Progress>,
+    // set when the runner wants the facts behind the answer
+    pub explanation: Option<Explanation>,
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckpointOptions {
+    pub dir: PathBuf,
+    // save a snapshot every n steps, never if None
+    pub every: Option<u64>,
+    pub resume: bool,
+}
+
+impl CheckpointOptions {
+    pub fn new(every: Option<u64>, resume: bool) -> Self {
+        CheckpointOptions { dir: PathBuf::from("./target/aoc-checkpoints"), every, resume }
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Context>> = const { RefCell::new(None) };
+}
+
+struct Restore(Option<Context>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CURRENT.set(self.0.take());
+    }
+}
+
+// runs f with context as the current context of this thread
+pub fn scope<T>(context: Context, f: impl FnOnce() -> T) -> T {
+    let _restore = Restore(CURRENT.replace(Some(context)));
+    f()
+}
+
+pub fn with<T>(f: impl FnOnce(Option<&Context>) -> T) -> T {
+    CURRENT.with_borrow(|context| f(context.as_ref()))
+}
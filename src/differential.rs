@@ -0,0 +1,160 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+
+use crate::registry::{Day, Solver};
+use crate::runner;
+
+// SplitMix64, generated inputs only need to be reproducible from their seed
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // uniform in [low, high]
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + (self.next_u64() % (high.abs_diff(low) + 1)) as i64
+    }
+
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.next_u64() as usize % (i + 1));
+        }
+    }
+}
+
+pub struct Outcome {
+    pub name: &'static str,
+    pub answer: Result<String, String>,
+    pub time_ms: f64,
+}
+
+// the main solver first, then the alternatives registered for the part
+pub fn implementations(day: &Day, part: u32) -> Vec<(&'static str, Solver)> {
+    let alternatives = day.alternatives.iter().filter(|alternative| alternative.part == part);
+    day.part(part).map(|solver| ("main", solver)).into_iter().chain(alternatives.map(|alternative| (alternative.name, alternative.solver))).collect()
+}
+
+pub fn parts_with_alternatives(day: &Day) -> Vec<u32> {
+    (1..=2).filter(|&part| day.alternatives.iter().any(|alternative| alternative.part == part)).collect()
+}
+
+pub fn compare(day: &Day, part: u32, input: &str) -> Vec<Outcome> {
+    implementations(day, part)
+        .into_iter()
+        .map(|(name, solver)| {
+            let _span = tracing::info_span!("implementation", day = day.day, part, name).entered();
+            let start = Instant::now();
+            let answer = match panic::catch_unwind(AssertUnwindSafe(|| solver(input))) {
+                Ok(Ok(answer)) => Ok(answer.to_line()),
+                Ok(Err(e)) => Err(format!("error: {:#}", e)),
+                Err(payload) => Err(format!("panic: {}", runner::panic_message(payload.as_ref()))),
+            };
+            Outcome { name, answer, time_ms: (start.elapsed().as_secs_f64() * 1e6).round() / 1e3 }
+        })
+        .collect()
+}
+
+pub fn agree(outcomes: &[Outcome]) -> bool {
+    match outcomes.first() {
+        Some(Outcome { answer: Ok(first), .. }) => outcomes.iter().all(|outcome| outcome.answer.as_ref() == Ok(first)),
+        _ => false,
+    }
+}
+
+pub struct DiffOptions {
+    pub inputs: Vec<String>,
+    pub generated: usize,
+    pub seed: u64,
+}
+
+fn report(day: &Day, part: u32, input_name: &str, outcomes: &[Outcome]) {
+    let status = if agree(outcomes) { "agree".green() } else { "DISAGREE".red().bold() };
+    println!("{} day{:02} part {} [{}] {}", day.year, day.day, part, input_name, status);
+    for outcome in outcomes {
+        let answer = match &outcome.answer {
+            Ok(answer) => answer.clone(),
+            Err(e) => e.red().to_string(),
+        };
+        println!("    {:<12} {} ({:.3} ms)", outcome.name, answer, outcome.time_ms);
+    }
+}
+
+pub fn diff(days: &[&Day], options: &DiffOptions) -> Result<()> {
+    // panics are reported as outcomes, the default hook would only add noise
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = diff_days(days, options);
+    panic::set_hook(hook);
+    match result? {
+        0 => Ok(()),
+        n => Err(anyhow!("{} comparison(s) disagreed", n)),
+    }
+}
+
+fn diff_days(days: &[&Day], options: &DiffOptions) -> Result<usize> {
+    let mut disagreements = 0;
+    for day in days {
+        let parts = parts_with_alternatives(day);
+        if parts.is_empty() {
+            println!("{} day{:02} has no alternative implementations", day.year, day.day);
+            continue;
+        }
+        let inputs = match options.inputs.is_empty() {
+            true => runner::available_inputs(day.year, day.day)?,
+            false => options.inputs.clone(),
+        };
+        for input_name in inputs {
+            let input = match std::fs::read_to_string(runner::input_path(day.year, day.day, &input_name)) {
+                Ok(input) => input,
+                Err(e) => {
+                    println!("{} day{:02} [{}] {}: {}", day.year, day.day, input_name, "skipped".yellow(), e);
+                    continue;
+                }
+            };
+            for &part in &parts {
+                let outcomes = compare(day, part, &input);
+                if !agree(&outcomes) {
+                    disagreements += 1;
+                }
+                report(day, part, &input_name, &outcomes);
+            }
+        }
+        let Some(generate) = day.generate else {
+            continue;
+        };
+        // generated inputs are only shown when they disagree, the seed reproduces them
+        for &part in &parts {
+            let mut failed = 0;
+            for seed in options.seed..options.seed + options.generated as u64 {
+                let outcomes = compare(day, part, &generate(&mut Rng::new(seed)));
+                if !agree(&outcomes) {
+                    failed += 1;
+                    report(day, part, &format!("seed {}", seed), &outcomes);
+                    println!("    reproduce with `aoc diff {} {} --seed {} --generated 1`", day.year, day.day, seed);
+                }
+            }
+            let summary = format!("{}/{} generated inputs agree", options.generated - failed, options.generated);
+            let summary = if failed == 0 { summary.green() } else { summary.red() };
+            println!("{} day{:02} part {} {}", day.year, day.day, part, summary);
+            disagreements += failed;
+        }
+    }
+    Ok(disagreements)
+}
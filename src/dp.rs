@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::{Index, IndexMut};
+
+use crate::alloc::format_bytes;
+
+// a key of a dense table, laid out row major in a table of the given shape, bounds are only
+// checked in debug builds since this is on the hot path of every lookup
+pub trait DenseKey: Copy + Hash + Eq {
+    fn offset(&self, shape: &[usize]) -> usize;
+}
+
+impl DenseKey for usize {
+    fn offset(&self, shape: &[usize]) -> usize {
+        debug_assert!(*self < shape[0], "index {} out of bounds for shape {:?}", self, shape);
+        *self
+    }
+}
+
+macro_rules! impl_dense_key_for_tuple {
+    ($($i:tt: $t:ident),*) => {
+        impl DenseKey for ($($t,)*) {
+            fn offset(&self, shape: &[usize]) -> usize {
+                let mut offset = 0;
+                $(
+                    debug_assert!(self.$i < shape[$i], "index {:?} out of bounds for shape {:?}", self, shape);
+                    offset = offset * shape[$i] + self.$i;
+                )*
+                offset
+            }
+        }
+    };
+}
+
+impl_dense_key_for_tuple!(0: usize, 1: usize);
+impl_dense_key_for_tuple!(0: usize, 1: usize, 2: usize);
+impl_dense_key_for_tuple!(0: usize, 1: usize, 2: usize, 3: usize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Storage {
+    // every key of the shape gets a cell up front
+    #[default]
+    Dense,
+    // only keys that were set take memory, for tables that are mostly empty
+    Sparse,
+}
+
+// what memoization needs from a table, implemented by hash maps and `DpTable`
+pub trait Table<K, V> {
+    fn get(&self, key: &K) -> Option<&V>;
+    fn set(&mut self, key: K, value: V);
+    fn remove(&mut self, key: &K);
+    fn len(&self) -> usize;
+    // what the table holds on to, hash maps are estimated from their capacity
+    fn memory_bytes(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // logs size and memory of the table, visible with -vv
+    fn report(&self, name: &str) {
+        tracing::debug!(table = name, entries = self.len(), memory = %format_bytes(self.memory_bytes() as u64), "dp table");
+    }
+}
+
+impl<K: Hash + Eq, V> Table<K, V> for HashMap<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        HashMap::get(self, key)
+    }
+
+    fn set(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+
+    fn remove(&mut self, key: &K) {
+        HashMap::remove(self, key);
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn memory_bytes(&self) -> usize {
+        self.capacity() * (std::mem::size_of::<(K, V)>() + 1)
+    }
+}
+
+// dp table over typed keys that stores its values densely or in a hash map
+#[derive(Debug, Clone)]
+pub enum DpTable<K, V> {
+    // a bit per cell marks the cells that were set, so the values need no `Option`, cells that
+    // are not set hold the default
+    Dense { shape: Vec<usize>, cells: Vec<V>, filled: Vec<u64>, len: usize },
+    Sparse(HashMap<K, V>),
+}
+
+impl<K: DenseKey, V: Clone + Default> DpTable<K, V> {
+    pub fn new(storage: Storage, shape: &[usize]) -> Self {
+        match storage {
+            Storage::Dense => {
+                let size: usize = shape.iter().product();
+                DpTable::Dense { shape: shape.to_vec(), cells: vec![V::default(); size], filled: vec![0; size.div_ceil(64)], len: 0 }
+            }
+            Storage::Sparse => DpTable::Sparse(HashMap::new()),
+        }
+    }
+
+    // the value at key, or the default for keys that were never set, dense tables skip the filled bit
+    #[inline]
+    pub fn at(&self, key: K) -> V
+    where
+        V: Copy,
+    {
+        match self {
+            DpTable::Dense { shape, cells, .. } => cells[key.offset(shape)],
+            DpTable::Sparse(map) => map.get(&key).copied().unwrap_or_default(),
+        }
+    }
+}
+
+impl<K: DenseKey, V: Default> Table<K, V> for DpTable<K, V> {
+    fn get(&self, key: &K) -> Option<&V> {
+        match self {
+            DpTable::Dense { shape, cells, filled, .. } => {
+                let offset = key.offset(shape);
+                (filled[offset / 64] & 1 << (offset % 64) != 0).then(|| &cells[offset])
+            }
+            DpTable::Sparse(map) => map.get(key),
+        }
+    }
+
+    fn set(&mut self, key: K, value: V) {
+        match self {
+            DpTable::Dense { shape, cells, filled, len } => {
+                let offset = key.offset(shape);
+                *len += (filled[offset / 64] & 1 << (offset % 64) == 0) as usize;
+                filled[offset / 64] |= 1 << (offset % 64);
+                cells[offset] = value;
+            }
+            DpTable::Sparse(map) => {
+                map.insert(key, value);
+            }
+        }
+    }
+
+    fn remove(&mut self, key: &K) {
+        match self {
+            DpTable::Dense { shape, cells, filled, len } => {
+                let offset = key.offset(shape);
+                *len -= (filled[offset / 64] & 1 << (offset % 64) != 0) as usize;
+                filled[offset / 64] &= !(1 << (offset % 64));
+                cells[offset] = V::default();
+            }
+            DpTable::Sparse(map) => {
+                map.remove(key);
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            DpTable::Dense { len, .. } => *len,
+            DpTable::Sparse(map) => map.len(),
+        }
+    }
+
+    fn memory_bytes(&self) -> usize {
+        match self {
+            DpTable::Dense { cells, filled, .. } => cells.capacity() * std::mem::size_of::<V>() + filled.capacity() * 8,
+            DpTable::Sparse(map) => Table::memory_bytes(map),
+        }
+    }
+}
+
+// computes the value of a key, recursing through `Memo::get`
+pub type Recurrence<K, V, C, T> = fn(&mut Memo<K, V, C, T>, &C, &K) -> V;
+
+// memoized recursion over the keys of a table
+pub struct Memo<K, V, C: ?Sized, T = HashMap<K, V>> {
+    table: T,
+    f: Recurrence<K, V, C, T>,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone, C: ?Sized> Memo<K, V, C> {
+    pub fn new(f: Recurrence<K, V, C, HashMap<K, V>>) -> Self {
+        Self::with_table(HashMap::new(), f)
+    }
+}
+
+impl<K: Clone, V: Clone, C: ?Sized, T: Table<K, V>> Memo<K, V, C, T> {
+    pub fn with_table(table: T, f: Recurrence<K, V, C, T>) -> Self {
+        Memo { table, f, hits: 0, misses: 0 }
+    }
+
+    pub fn get(&mut self, context: &C, key: &K) -> V {
+        if let Some(value) = self.table.get(key) {
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        let value = (self.f)(self, context, key);
+        self.table.set(key.clone(), value.clone());
+        value
+    }
+
+    // drops a memoized value whose inputs changed
+    pub fn forget(&mut self, key: &K) {
+        self.table.remove(key);
+    }
+
+    pub fn table(&self) -> &T {
+        &self.table
+    }
+
+    pub fn report(&self, name: &str) {
+        self.table.report(name);
+        tracing::debug!(memo = name, hits = self.hits, misses = self.misses, "memo");
+    }
+}
+
+// a set of at most 64 small integers, bit i is element i
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Mask(pub u64);
+
+impl Mask {
+    // all of 0..n
+    pub fn full(n: usize) -> Self {
+        Mask(if n >= 64 { u64::MAX } else { (1 << n) - 1 })
+    }
+
+    pub fn contains(self, i: usize) -> bool {
+        self.0 & 1 << i != 0
+    }
+
+    pub fn with(self, i: usize) -> Self {
+        Mask(self.0 | 1 << i)
+    }
+
+    pub fn without(self, i: usize) -> Self {
+        Mask(self.0 & !(1 << i))
+    }
+
+    pub fn is_disjoint(self, other: Mask) -> bool {
+        self.0 & other.0 == 0
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn ones(self) -> impl Iterator<Item = usize> {
+        let mut rest = self.0;
+        std::iter::from_fn(move || {
+            let i = rest.trailing_zeros() as usize;
+            (rest != 0).then(|| {
+                rest &= rest - 1;
+                i
+            })
+        })
+    }
+}
+
+impl DenseKey for Mask {
+    fn offset(&self, shape: &[usize]) -> usize {
+        (self.0 as usize).offset(shape)
+    }
+}
+
+// one value per subset of 0..n
+#[derive(Debug, Clone)]
+pub struct MaskTable<V> {
+    bits: usize,
+    cells: Vec<V>,
+}
+
+impl<V: Clone> MaskTable<V> {
+    pub fn new(bits: usize, value: V) -> Self {
+        MaskTable { bits, cells: vec![value; 1 << bits] }
+    }
+
+    pub fn bits(&self) -> usize {
+        self.bits
+    }
+
+    pub fn masks(&self) -> impl Iterator<Item = Mask> {
+        (0..self.cells.len() as u64).map(Mask)
+    }
+
+    // replaces every value by the combination of the values of all its subsets
+    pub fn fold_subsets(&mut self, combine: impl Fn(&V, &V) -> V) {
+        for i in 0..self.bits {
+            for mask in 0..self.cells.len() {
+                if mask & 1 << i != 0 {
+                    self.cells[mask] = combine(&self.cells[mask], &self.cells[mask ^ 1 << i]);
+                }
+            }
+        }
+    }
+
+    pub fn memory_bytes(&self) -> usize {
+        self.cells.capacity() * std::mem::size_of::<V>()
+    }
+
+    pub fn report(&self, name: &str) {
+        tracing::debug!(table = name, bits = self.bits, memory = %format_bytes(self.memory_bytes() as u64), "mask table");
+    }
+}
+
+impl<V> Index<Mask> for MaskTable<V> {
+    type Output = V;
+
+    fn index(&self, mask: Mask) -> &V {
+        &self.cells[mask.0 as usize]
+    }
+}
+
+impl<V> IndexMut<Mask> for MaskTable<V> {
+    fn index_mut(&mut self, mask: Mask) -> &mut V {
+        &mut self.cells[mask.0 as usize]
+    }
+}
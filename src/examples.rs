@@ -0,0 +1,149 @@
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use regex::Regex;
+
+use crate::ledger::Ledger;
+use crate::runner::{self, Answer};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub text: String,
+    // the prose right before the block, e.g. "Here is a larger example:"
+    pub lead: String,
+}
+
+// one `<article>` of a puzzle page, the second one only exists once part 1 is solved
+#[derive(Debug, Clone, PartialEq)]
+pub struct Article {
+    pub blocks: Vec<Block>,
+    pub answer: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ImportOptions {
+    pub dry_run: bool,
+    // replace ledger entries that disagree with the page
+    pub force: bool,
+}
+
+fn decode(html: &str) -> String {
+    let text = Regex::new(r"<[^>]*>").unwrap().replace_all(html, "");
+    text.replace("&lt;", "<").replace("&gt;", ">").replace("&quot;", "\"").replace("&#39;", "'").replace("&amp;", "&")
+}
+
+pub fn parse(html: &str) -> Vec<Article> {
+    let article = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let block = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    // some pages emphasize the answer without the code around it, a bare <em> only counts when it holds a number
+    let answer = Regex::new(r"(?s)<code><em>(.*?)</em></code>|<em><code>(.*?)</code></em>|<em>\s*(-?[0-9]+)\s*</em>").unwrap();
+    let mut articles: Vec<&str> = article.captures_iter(html).map(|c| c.get(1).unwrap().as_str()).collect();
+    if articles.is_empty() {
+        articles.push(html);
+    }
+    articles
+        .into_iter()
+        .map(|content| {
+            let mut previous_end = 0;
+            let blocks = block
+                .captures_iter(content)
+                .map(|c| {
+                    let whole = c.get(0).unwrap();
+                    let lead = decode(&content[previous_end..whole.start()]);
+                    previous_end = whole.end();
+                    let lead = lead.trim().rsplit('\n').next().unwrap_or_default().to_string();
+                    Block { text: decode(c.get(1).unwrap().as_str()), lead }
+                })
+                .collect();
+            // the puzzle asks its question last, so the last emphasized answer is the example's
+            let answer = answer
+                .captures_iter(content)
+                .last()
+                .and_then(|c| c.get(1).or(c.get(2)).or(c.get(3)))
+                .map(|m| decode(m.as_str()).trim().to_string());
+            Article { blocks, answer }
+        })
+        .collect()
+}
+
+// the first block introduced as an example, most pages show the input right after "For example:"
+fn example_block(article: &Article) -> Option<&Block> {
+    article.blocks.iter().find(|block| block.lead.to_lowercase().contains("example")).or(article.blocks.first())
+}
+
+fn normalized(text: &str) -> &str {
+    text.trim_end()
+}
+
+// the example file already holding this text, or the next free example name
+fn example_name(year: u32, day: u32, text: &str, taken: &[(String, String)]) -> String {
+    if let Some((name, _)) = taken.iter().find(|(_, content)| normalized(content) == normalized(text)) {
+        return name.clone();
+    }
+    let existing = runner::available_inputs(year, day).unwrap_or_default();
+    let free = |name: &String| !existing.contains(name) && !taken.iter().any(|(taken, _)| taken == name);
+    std::iter::once("example".to_string()).chain((2..).map(|n| format!("example{}", n))).find(free).unwrap()
+}
+
+pub fn import(year: u32, day: u32, html_path: &Path, options: ImportOptions) -> Result<()> {
+    let articles = parse(&std::fs::read_to_string(html_path)?);
+    let Some(part1) = articles.first().and_then(example_block) else {
+        bail!("no <pre><code> blocks in {}", html_path.display());
+    };
+    // part 2 only gets its own example when it introduces a new one
+    let part2 = articles.get(1).and_then(|article| {
+        article.blocks.iter().find(|block| block.lead.to_lowercase().contains("example") && normalized(&block.text) != normalized(&part1.text))
+    });
+
+    let mut taken: Vec<(String, String)> = runner::available_inputs(year, day)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|name| name.starts_with("example"))
+        .filter_map(|name| Some((name.clone(), std::fs::read_to_string(runner::input_path(year, day, &name)).ok()?)))
+        .collect();
+    let mut ledger = Ledger::load(year, day)?;
+    let mut conflicts = 0;
+    for (part, article) in articles.iter().take(2).enumerate() {
+        let part = part as u32 + 1;
+        let block = match part {
+            2 => part2.unwrap_or(part1),
+            _ => part1,
+        };
+        let name = example_name(year, day, &block.text, &taken);
+        let path = runner::input_path(year, day, &name);
+        if !taken.iter().any(|(taken, _)| *taken == name) {
+            println!("{} {} ({} lines)", if options.dry_run { "would write" } else { "writing" }, path, block.text.lines().count());
+            if !options.dry_run {
+                std::fs::create_dir_all(runner::data_dir(year))?;
+                std::fs::write(&path, &block.text)?;
+            }
+            taken.push((name.clone(), block.text.clone()));
+        }
+        let Some(answer) = &article.answer else {
+            println!("part {}: no emphasized answer found", part);
+            continue;
+        };
+        let Ok(answer) = answer.parse::<Answer>();
+        match ledger.get(&name, part) {
+            Some(existing) if *existing == answer => println!("part {}: {} {} already in the ledger", part, name, answer),
+            Some(existing) if !options.force => {
+                conflicts += 1;
+                println!("part {}: ledger has {} for {} but the page says {}, use --force to replace it", part, existing, name, answer);
+            }
+            _ => {
+                println!("part {}: {} {}", part, name, answer);
+                ledger.insert(&name, part, answer);
+            }
+        }
+    }
+    if articles.len() < 2 {
+        println!("part 2 is not on the page yet, import it again once it is unlocked");
+    }
+    if !options.dry_run {
+        ledger.save(year, day)?;
+    }
+    match conflicts {
+        0 => Ok(()),
+        n => bail!("{} ledger entr{} left unchanged", n, if n == 1 { "y" } else { "ies" }),
+    }
+}
@@ -0,0 +1,26 @@
+use std::sync::{Arc, Mutex};
+
+use crate::context;
+
+// the facts behind an answer, collected while `aoc run --explain` solves a part and printed
+// below it, shared so parallel workers add to the same trace
+#[derive(Debug, Clone, Default)]
+pub struct Explanation(Arc<Mutex<Vec<String>>>);
+
+impl Explanation {
+    pub fn lines(&self) -> Vec<String> {
+        self.0.lock().unwrap().clone()
+    }
+}
+
+// whether the current part is explained, for solvers that need extra work to say why
+pub fn enabled() -> bool {
+    context::with(|context| context.is_some_and(|context| context.explanation.is_some()))
+}
+
+// adds a line to the explanation of the current part, it is only built when explaining
+pub fn line(line: impl FnOnce() -> String) {
+    if let Some(explanation) = context::with(|context| context.and_then(|context| context.explanation.clone())) {
+        explanation.0.lock().unwrap().push(line());
+    }
+}
@@ -0,0 +1,94 @@
+use anyhow::{bail, Result};
+
+pub type Position = (usize, usize);
+
+// row major grid, positions are (row, column)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    pub width: usize,
+    pub height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn parse(input: &str, f: impl Fn(char) -> T) -> Result<Self> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let before = cells.len();
+            cells.extend(line.chars().map(&f));
+            match width {
+                Some(width) if width != cells.len() - before => bail!("line {} has a different width", height + 1),
+                _ => width = Some(cells.len() - before),
+            }
+            height += 1;
+        }
+        Ok(Grid { width: width.unwrap_or(0), height, cells })
+    }
+
+    pub fn get(&self, (row, col): Position) -> Option<&T> {
+        (row < self.height && col < self.width).then(|| &self.cells[row * self.width + col])
+    }
+
+    pub fn get_mut(&mut self, (row, col): Position) -> Option<&mut T> {
+        (row < self.height && col < self.width).then(|| &mut self.cells[row * self.width + col])
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn find(&self, f: impl Fn(&T) -> bool) -> Option<Position> {
+        self.iter().find(|(_, cell)| f(cell)).map(|(pos, _)| pos)
+    }
+
+    fn offsets(&self, (row, col): Position, offsets: &'static [(isize, isize)]) -> impl Iterator<Item = Position> {
+        let (width, height) = (self.width, self.height);
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let row = row.checked_add_signed(dr)?;
+            let col = col.checked_add_signed(dc)?;
+            (row < height && col < width).then_some((row, col))
+        })
+    }
+
+    pub fn neighbours4(&self, pos: Position) -> impl Iterator<Item = Position> {
+        self.offsets(pos, &[(-1, 0), (1, 0), (0, -1), (0, 1)])
+    }
+
+    pub fn neighbours8(&self, pos: Position) -> impl Iterator<Item = Position> {
+        self.offsets(pos, &[(-1, -1), (-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0), (1, 1)])
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid { width: self.width, height: self.height, cells: self.cells.iter().map(f).collect() }
+    }
+}
+
+impl<T> std::ops::Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Position) -> &T {
+        self.get(pos).unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl<T> std::ops::IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, pos: Position) -> &mut T {
+        self.get_mut(pos).unwrap_or_else(|| panic!("{:?} is outside of the grid", pos))
+    }
+}
+
+impl std::fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,21 @@
+use std::borrow::Cow;
+
+// inputs saved by browsers and editors differ in line endings, BOMs and trailing whitespace,
+// solvers all see `\n` line endings, no trailing whitespace and exactly one final newline
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let text = input.strip_prefix('\u{feff}').unwrap_or(input);
+    let mut normalized = String::with_capacity(text.len() + 1);
+    // trimming the end of every line also drops the `\r` of `\r\n`
+    for line in text.split('\n') {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    match normalized == input {
+        true => Cow::Borrowed(input),
+        false => Cow::Owned(normalized),
+    }
+}
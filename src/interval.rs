@@ -0,0 +1,32 @@
+// closed integer intervals [start, end]
+pub type Interval = (i64, i64);
+
+// sorts and merges overlapping or touching intervals, empty intervals are dropped
+pub fn merge(intervals: impl IntoIterator<Item = Interval>) -> Vec<Interval> {
+    let mut intervals: Vec<Interval> = intervals.into_iter().filter(|(start, end)| start <= end).collect();
+    intervals.sort_unstable();
+    let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+pub fn covered(merged: &[Interval]) -> u64 {
+    merged.iter().map(|(start, end)| start.abs_diff(*end) + 1).sum()
+}
+
+// first uncovered value in [start, end], merged must come from `merge`
+pub fn first_gap(merged: &[Interval], (start, end): Interval) -> Option<i64> {
+    let mut x = start;
+    for interval in merged.iter().skip_while(|(_, e)| *e < start) {
+        if interval.0 > x {
+            break;
+        }
+        x = interval.1.checked_add(1)?;
+    }
+    (x <= end).then_some(x)
+}
@@ -0,0 +1,68 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use anyhow::{bail, Result};
+
+use crate::runner::{input_path, Answer};
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Ledger {
+    entries: BTreeMap<(String, u32), Answer>,
+}
+
+impl Ledger {
+    pub fn path(year: u32, day: u32) -> String {
+        input_path(year, day, "answers")
+    }
+
+    pub fn load(year: u32, day: u32) -> Result<Self> {
+        Self::read(Path::new(&Self::path(year, day)))
+    }
+
+    pub fn save(&self, year: u32, day: u32) -> Result<()> {
+        Ok(std::fs::write(Self::path(year, day), self.to_string())?)
+    }
+
+    // a ledger kept somewhere else, like the one next to a batch of inputs, empty if it does not exist
+    pub fn read(path: &Path) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => content.parse(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn get(&self, input: &str, part: u32) -> Option<&Answer> {
+        self.entries.get(&(input.to_string(), part))
+    }
+
+    pub fn insert(&mut self, input: &str, part: u32, answer: Answer) {
+        self.entries.insert((input.to_string(), part), answer);
+    }
+}
+
+impl std::str::FromStr for Ledger {
+    type Err = anyhow::Error;
+
+    // one entry per line: `<input> <part> <answer>`, answers in their line form
+    fn from_str(content: &str) -> Result<Self> {
+        let mut ledger = Self::default();
+        for line in content.lines().filter(|line| !line.trim().is_empty()) {
+            let mut fields = line.splitn(3, ' ');
+            match (fields.next(), fields.next().map(str::parse::<u32>), fields.next()) {
+                (Some(input), Some(Ok(part)), Some(answer)) => ledger.insert(input, part, answer.parse()?),
+                _ => bail!("malformed ledger line {:?}", line),
+            }
+        }
+        Ok(ledger)
+    }
+}
+
+impl std::fmt::Display for Ledger {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for ((input, part), answer) in &self.entries {
+            writeln!(f, "{} {} {}", input, part, answer.to_line())?;
+        }
+        Ok(())
+    }
+}
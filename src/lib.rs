@@ -1,6 +1,42 @@
 use anyhow::Result;
 use std::str::FromStr;
 
+#[macro_use]
+pub mod registry;
+pub mod alloc;
+pub mod batch;
+pub mod bench;
+pub mod branch_bound;
+pub mod cache;
+pub mod checked;
+pub mod checkpoint;
+pub mod context;
+pub mod differential;
+pub mod dp;
+pub mod examples;
+pub mod explain;
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod ledger;
+pub mod lint;
+pub mod parallel;
+pub mod progress;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod repl;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod simulation;
+#[cfg(feature = "server")]
+pub mod server;
+pub mod snapshot;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod watch;
+pub mod y2022;
+
 pub fn read_one_per_line<T>(path: &str) -> Result<Vec<T>>
 where
 T: FromStr,
@@ -0,0 +1,58 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+use crate::registry::Day;
+use crate::runner::{self, panic_message};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub assumption: &'static str,
+    // why the input breaks the assumption, None if it holds
+    pub broken: Option<String>,
+}
+
+// runs every assumption of day against input, a check that panics counts as broken
+pub fn check(day: &Day, input: &str) -> Vec<Finding> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let findings = day
+        .assumptions
+        .iter()
+        .map(|assumption| {
+            let broken = match panic::catch_unwind(AssertUnwindSafe(|| (assumption.check)(input))) {
+                Ok(Ok(())) => None,
+                Ok(Err(e)) => Some(format!("{:#}", e)),
+                Err(payload) => Some(format!("check panicked: {}", panic_message(payload.as_ref()))),
+            };
+            Finding { assumption: assumption.name, broken }
+        })
+        .collect();
+    panic::set_hook(hook);
+    findings
+}
+
+// `file` is a path or the name of one of the day's inputs, e.g. `example`
+pub fn lint_input(day: &Day, file: &str) -> Result<()> {
+    let path = match Path::new(file).exists() {
+        true => file.to_string(),
+        false => runner::input_path(day.year, day.day, file),
+    };
+    let input = std::fs::read_to_string(&path).map_err(|e| anyhow!("{}: {}", path, e))?;
+    if day.assumptions.is_empty() {
+        println!("{} day{:02} declares no input assumptions", day.year, day.day);
+        return Ok(());
+    }
+    let findings = check(day, &input);
+    for finding in &findings {
+        match &finding.broken {
+            None => println!("{} day{:02} [{}] {}: ok", day.year, day.day, path, finding.assumption),
+            Some(reason) => println!("{} day{:02} [{}] {}: broken, {}", day.year, day.day, path, finding.assumption, reason),
+        }
+    }
+    match findings.iter().filter(|finding| finding.broken.is_some()).count() {
+        0 => Ok(()),
+        n => Err(anyhow!("{} assumption(s) do not hold for {}", n, path)),
+    }
+}
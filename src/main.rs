@@ -1,3 +1,318 @@
-fn main() {
-    println!("Hello, world!");
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use libaoc::batch;
+use libaoc::bench;
+use libaoc::context::CheckpointOptions;
+use libaoc::differential::{self, DiffOptions};
+use libaoc::examples::{self, ImportOptions};
+use libaoc::lint;
+use libaoc::registry::{self, Day};
+use libaoc::repl;
+use libaoc::runner::{self, Format, RunOptions};
+use libaoc::scaffold;
+use libaoc::snapshot::{self, Mode};
+use libaoc::watch::{self, WatchOptions};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: libaoc::alloc::CountingAllocator = libaoc::alloc::CountingAllocator;
+
+const USAGE: &str = "usage:
+    aoc [-v...] run [<year>] (<day>... | --all) [--input <name>] [--format text|json] [--no-cache] [--explain]
+        [--checkpoint-every <steps>] [--resume] [--time-limit <seconds>] [--no-progress]
+    aoc [-v...] bench [<year>] (<day>... | --all) [--input <name>] [--iterations <n>] [--format text|json]
+    aoc [-v...] watch [<year>] <day> [--input <name>]... [--release]
+    aoc [-v...] batch [<year>] (<day>... | --all) [--dir <path>] [--time-limit <seconds>]
+    aoc [-v...] diff [<year>] (<day>... | --all) [--input <name>]... [--generated <n>] [--seed <n>]
+    aoc [-v...] repl [<year>] <day> [--input <name>]
+    aoc [-v...] examples import [<year>] <day> <saved.html> [--dry-run] [--force]
+    aoc [-v...] lint-input [<year>] <day> <file>
+    aoc [-v...] snapshot [check|review|accept] [<year>] (<day>... | --all) [--input <name>]...
+    aoc [-v...] serve [--addr <host:port>] [--time-limit <seconds>]
+    aoc [-v...] tui [--time-limit <seconds>]
+    aoc new <year> <day>";
+
+fn parse_number(arg: &str) -> Result<u32> {
+    arg.parse().map_err(|_| anyhow!(USAGE))
+}
+
+// a leading number above 25 selects the year, otherwise the latest year is used
+fn split_year(numbers: &[u32]) -> (Option<u32>, &[u32]) {
+    match numbers.split_first() {
+        Some((&year, days)) if year > 25 => (Some(year), days),
+        _ => (None, numbers),
+    }
+}
+
+fn get_day(year: u32, day: u32) -> Result<&'static Day> {
+    registry::get(year, day).ok_or_else(|| anyhow!("{} day {} is not registered", year, day))
+}
+
+fn select_days(numbers: &[u32], all: bool) -> Result<Vec<&'static Day>> {
+    Ok(match (split_year(numbers), all) {
+        ((None, []), true) => registry::all().collect(),
+        ((Some(year), []), true) => registry::year(year).ok_or_else(|| anyhow!("year {} is not registered", year))?.days.iter().collect(),
+        ((year, days), false) if !days.is_empty() => {
+            let year = year.unwrap_or_else(registry::latest_year);
+            days.iter().map(|&day| get_day(year, day)).collect::<Result<_>>()?
+        }
+        _ => bail!(USAGE),
+    })
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let mut numbers = Vec::new();
+    let mut all = false;
+    let mut input = "input".to_string();
+    let mut format = Format::Text;
+    let mut use_cache = true;
+    let mut checkpoint_every = None;
+    let mut resume = false;
+    let mut options = RunOptions::default();
+    options.progress.bar = true;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--input" => input = args.next().ok_or_else(|| anyhow!(USAGE))?.clone(),
+            "--format" => format = args.next().ok_or_else(|| anyhow!(USAGE))?.parse()?,
+            "--no-cache" => use_cache = false,
+            "--checkpoint-every" => checkpoint_every = Some(parse_number(args.next().ok_or_else(|| anyhow!(USAGE))?)? as u64),
+            "--resume" => resume = true,
+            "--time-limit" => options.progress.time_limit = Some(Duration::from_secs(parse_number(args.next().ok_or_else(|| anyhow!(USAGE))?)? as u64)),
+            "--no-progress" => options.progress.bar = false,
+            "--explain" => options.explain = true,
+            number => numbers.push(parse_number(number)?),
+        }
+    }
+    options.checkpoints = (checkpoint_every.is_some() || resume).then(|| CheckpointOptions::new(checkpoint_every, resume));
+    // a json consumer reads stdout, keep the terminal quiet for it
+    options.progress.bar &= format == Format::Text;
+    options.progress.cancel_on_ctrl_c()?;
+    runner::run_days(&select_days(&numbers, all)?, &input, format, use_cache, &options)
+}
+
+fn bench(args: &[String]) -> Result<()> {
+    let mut numbers = Vec::new();
+    let mut all = false;
+    let mut input = "input".to_string();
+    let mut iterations = 10;
+    let mut format = Format::Text;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--input" => input = args.next().ok_or_else(|| anyhow!(USAGE))?.clone(),
+            "--iterations" => iterations = parse_number(args.next().ok_or_else(|| anyhow!(USAGE))?)? as usize,
+            "--format" => format = args.next().ok_or_else(|| anyhow!(USAGE))?.parse()?,
+            number => numbers.push(parse_number(number)?),
+        }
+    }
+    bench::bench_days(&select_days(&numbers, all)?, &input, iterations, format)
+}
+
+fn watch(args: &[String]) -> Result<()> {
+    let mut numbers = Vec::new();
+    let mut inputs = Vec::new();
+    let mut release = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => inputs.push(args.next().ok_or_else(|| anyhow!(USAGE))?.clone()),
+            "--release" => release = true,
+            number => numbers.push(parse_number(number)?),
+        }
+    }
+    let (year, day) = match split_year(&numbers) {
+        (year, &[day]) => (year.unwrap_or_else(registry::latest_year), day),
+        _ => bail!(USAGE),
+    };
+    get_day(year, day)?;
+    watch::watch(WatchOptions { year, day, inputs, release })
+}
+
+fn batch(args: &[String]) -> Result<()> {
+    let mut numbers = Vec::new();
+    let mut all = false;
+    let mut dir = None;
+    let mut options = RunOptions::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--dir" => dir = Some(PathBuf::from(args.next().ok_or_else(|| anyhow!(USAGE))?)),
+            "--time-limit" => options.progress.time_limit = Some(Duration::from_secs(parse_number(args.next().ok_or_else(|| anyhow!(USAGE))?)? as u64)),
+            number => numbers.push(parse_number(number)?),
+        }
+    }
+    options.progress.cancel_on_ctrl_c()?;
+    batch::batch(&select_days(&numbers, all)?, dir.as_deref(), &options)
+}
+
+fn diff(args: &[String]) -> Result<()> {
+    let mut numbers = Vec::new();
+    let mut all = false;
+    let mut options = DiffOptions { inputs: Vec::new(), generated: 100, seed: 0 };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--input" => options.inputs.push(args.next().ok_or_else(|| anyhow!(USAGE))?.clone()),
+            "--generated" => options.generated = parse_number(args.next().ok_or_else(|| anyhow!(USAGE))?)? as usize,
+            "--seed" => options.seed = parse_number(args.next().ok_or_else(|| anyhow!(USAGE))?)? as u64,
+            number => numbers.push(parse_number(number)?),
+        }
+    }
+    differential::diff(&select_days(&numbers, all)?, &options)
+}
+
+fn repl(args: &[String]) -> Result<()> {
+    let mut numbers = Vec::new();
+    let mut input = "input".to_string();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = args.next().ok_or_else(|| anyhow!(USAGE))?.clone(),
+            number => numbers.push(parse_number(number)?),
+        }
+    }
+    match split_year(&numbers) {
+        (year, &[day]) => repl::repl(get_day(year.unwrap_or_else(registry::latest_year), day)?, &input),
+        _ => bail!(USAGE),
+    }
+}
+
+fn examples(args: &[String]) -> Result<()> {
+    let Some(("import", args)) = args.split_first().map(|(command, args)| (command.as_str(), args)) else {
+        bail!(USAGE);
+    };
+    let mut numbers = Vec::new();
+    let mut html = None;
+    let mut options = ImportOptions::default();
+    for arg in args {
+        match arg.as_str() {
+            "--dry-run" => options.dry_run = true,
+            "--force" => options.force = true,
+            arg => match arg.parse() {
+                Ok(number) => numbers.push(number),
+                Err(_) => html = Some(PathBuf::from(arg)),
+            },
+        }
+    }
+    match (split_year(&numbers), html) {
+        ((year, &[day]), Some(html)) => {
+            let year = year.unwrap_or_else(registry::latest_year);
+            get_day(year, day)?;
+            examples::import(year, day, &html, options)
+        }
+        _ => bail!(USAGE),
+    }
+}
+
+fn lint_input(args: &[String]) -> Result<()> {
+    let Some((file, numbers)) = args.split_last() else {
+        bail!(USAGE);
+    };
+    let numbers = numbers.iter().map(|arg| parse_number(arg)).collect::<Result<Vec<_>>>()?;
+    match split_year(&numbers) {
+        (year, &[day]) => lint::lint_input(get_day(year.unwrap_or_else(registry::latest_year), day)?, file),
+        _ => bail!(USAGE),
+    }
+}
+
+fn snapshot(args: &[String]) -> Result<()> {
+    let (mode, args) = match args.split_first().map(|(mode, rest)| (mode.as_str(), rest)) {
+        Some(("check", rest)) => (Mode::Check, rest),
+        Some(("review", rest)) => (Mode::Review, rest),
+        Some(("accept", rest)) => (Mode::Accept, rest),
+        _ => (Mode::Check, args),
+    };
+    let mut numbers = Vec::new();
+    let mut all = false;
+    let mut inputs = Vec::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--all" => all = true,
+            "--input" => inputs.push(args.next().ok_or_else(|| anyhow!(USAGE))?.clone()),
+            number => numbers.push(parse_number(number)?),
+        }
+    }
+    snapshot::snapshot(&select_days(&numbers, all)?, &inputs, mode)
+}
+
+#[cfg(feature = "server")]
+fn serve(args: &[String]) -> Result<()> {
+    let mut addr = "127.0.0.1:8080".to_string();
+    let mut options = RunOptions::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--addr" => addr = args.next().ok_or_else(|| anyhow!(USAGE))?.clone(),
+            "--time-limit" => options.progress.time_limit = Some(Duration::from_secs(parse_number(args.next().ok_or_else(|| anyhow!(USAGE))?)? as u64)),
+            _ => bail!(USAGE),
+        }
+    }
+    let server = libaoc::server::Server::bind(&addr, options)?;
+    println!("listening on http://{}", server.addr().map_or(addr, |addr| addr.to_string()));
+    server.run();
+    Ok(())
+}
+
+#[cfg(not(feature = "server"))]
+fn serve(_: &[String]) -> Result<()> {
+    bail!("aoc was built without the server feature, rebuild with --features server")
+}
+
+#[cfg(feature = "tui")]
+fn tui(verbosity: usize, args: &[String]) -> Result<()> {
+    let mut options = RunOptions::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--time-limit" => options.progress.time_limit = Some(Duration::from_secs(parse_number(args.next().ok_or_else(|| anyhow!(USAGE))?)? as u64)),
+            _ => bail!(USAGE),
+        }
+    }
+    let logs = libaoc::tui::LogBuffer::default();
+    // the tui owns the terminal, the log goes to a pane instead
+    runner::init_tracing_with_writer(verbosity.max(1), logs.clone());
+    libaoc::tui::tui(options, logs)
+}
+
+#[cfg(not(feature = "tui"))]
+fn tui(_: usize, _: &[String]) -> Result<()> {
+    bail!("aoc was built without the tui feature, rebuild with --features tui")
+}
+
+fn new(args: &[String]) -> Result<()> {
+    match args {
+        [year, day] => scaffold::new_day(parse_number(year)?, parse_number(day)?),
+        _ => bail!(USAGE),
+    }
+}
+
+fn main() -> Result<()> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let verbosity = runner::verbosity(&mut args);
+    if args.first().map(String::as_str) == Some("tui") {
+        return tui(verbosity, &args[1..]);
+    }
+    runner::init_tracing(verbosity);
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        Some("watch") => watch(&args[1..]),
+        Some("batch") => batch(&args[1..]),
+        Some("diff") => diff(&args[1..]),
+        Some("repl") => repl(&args[1..]),
+        Some("examples") => examples(&args[1..]),
+        Some("lint-input") => lint_input(&args[1..]),
+        Some("snapshot") => snapshot(&args[1..]),
+        Some("serve") => serve(&args[1..]),
+        Some("new") => new(&args[1..]),
+        _ => bail!(USAGE),
+    }
 }
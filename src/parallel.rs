@@ -0,0 +1,61 @@
+use std::ops::Range;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(feature = "parallel")]
+use crate::context::{self, Context};
+
+// hot loops that run on rayon with the `parallel` feature and in order without it, results
+// are combined in input order so both builds give the same answers
+
+// the caller's context without checkpoints, workers still see the deadline and ctrl-c but
+// never save snapshots that would overwrite each other
+#[cfg(feature = "parallel")]
+fn worker_context() -> Option<Context> {
+    context::with(|context| context.map(|context| Context { checkpoints: None, ..context.clone() }))
+}
+
+#[cfg(feature = "parallel")]
+fn in_context<R>(context: &Option<Context>, f: impl FnOnce() -> R) -> R {
+    match context {
+        Some(context) => context::scope(context.clone(), f),
+        None => f(),
+    }
+}
+
+// f applied to every item, in the order of the items
+#[cfg(feature = "parallel")]
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let context = worker_context();
+    items.par_iter().map(|item| in_context(&context, || f(item))).collect()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    items.iter().map(f).collect()
+}
+
+// the result for the first item that has one, even if a later item finishes sooner
+#[cfg(feature = "parallel")]
+pub fn find_map_first<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> Option<R> + Sync) -> Option<R> {
+    let context = worker_context();
+    items.par_iter().find_map_first(|item| in_context(&context, || f(item)))
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn find_map_first<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> Option<R> + Sync) -> Option<R> {
+    items.iter().find_map(f)
+}
+
+// the largest f(i) over the range, None if it is empty
+#[cfg(feature = "parallel")]
+pub fn max<R: Ord + Send>(range: Range<usize>, f: impl Fn(usize) -> R + Sync) -> Option<R> {
+    let context = worker_context();
+    range.into_par_iter().map(|i| in_context(&context, || f(i))).max()
+}
+
+#[cfg(not(feature = "parallel"))]
+pub fn max<R: Ord + Send>(range: Range<usize>, f: impl Fn(usize) -> R + Sync) -> Option<R> {
+    range.map(f).max()
+}
@@ -0,0 +1,125 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering::Relaxed};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressStyle};
+
+use crate::context::{self, Context};
+
+// how the runner wants a part to report back, shared by all parts of one run
+#[derive(Debug, Clone, Default)]
+pub struct ProgressOptions {
+    pub bar: bool,
+    pub time_limit: Option<Duration>,
+    // set from outside (ctrl-c, a server request) to ask the running part to stop
+    pub cancel: Arc<AtomicBool>,
+}
+
+impl ProgressOptions {
+    // ctrl-c cancels the running part, a second ctrl-c exits
+    pub fn cancel_on_ctrl_c(&self) -> Result<()> {
+        let cancel = self.cancel.clone();
+        ctrlc::set_handler(move || {
+            if cancel.swap(true, Relaxed) {
+                std::process::exit(130);
+            }
+        })?;
+        Ok(())
+    }
+
+    pub fn cancelled(&self) -> bool {
+        self.cancel.load(Relaxed)
+    }
+}
+
+// the per-part half of the options, installed in the context by the runner
+#[derive(Debug, Clone)]
+pub struct Progress {
+    bar: Option<ProgressBar>,
+    deadline: Option<(Instant, Duration)>,
+    cancel: Arc<AtomicBool>,
+}
+
+impl Progress {
+    pub fn start(options: &ProgressOptions, label: String) -> Self {
+        let bar = options.bar.then(|| {
+            let bar = ProgressBar::with_draw_target(None, ProgressDrawTarget::stderr());
+            bar.set_style(ProgressStyle::with_template("{prefix} [{elapsed_precise}] {wide_bar} {pos}/{len} {msg}").unwrap());
+            bar.set_prefix(label);
+            bar
+        });
+        Progress { bar, deadline: options.time_limit.map(|limit| (Instant::now() + limit, limit)), cancel: options.cancel.clone() }
+    }
+
+    pub fn finish(&self) {
+        if let Some(bar) = &self.bar {
+            bar.finish_and_clear();
+        }
+    }
+
+    fn check(&self) -> Result<(), Stopped> {
+        match self.deadline {
+            _ if self.cancel.load(Relaxed) => Err(Stopped::Cancelled),
+            Some((deadline, limit)) if Instant::now() >= deadline => Err(Stopped::TimedOut(limit)),
+            _ => Ok(()),
+        }
+    }
+}
+
+// returned by solvers that gave up because they were asked to, the runner turns it into a status
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stopped {
+    TimedOut(Duration),
+    Cancelled,
+}
+
+impl fmt::Display for Stopped {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Stopped::TimedOut(limit) => write!(f, "timed out after {:?}", limit),
+            Stopped::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for Stopped {}
+
+// what a solver has done so far, `best` is its best bound on the answer if it has one
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Update {
+    pub round: u64,
+    pub rounds: u64,
+    pub states: u64,
+    pub best: Option<u64>,
+}
+
+fn with_progress<T>(f: impl FnOnce(&Progress) -> T) -> Option<T> {
+    context::with(|context| match context {
+        Some(Context { progress: Some(progress), .. }) => Some(f(progress)),
+        _ => None,
+    })
+}
+
+// errors with `Stopped` once the time limit has passed or the part was cancelled, cheap enough
+// for inner loops, outside of the runner it never fails
+pub fn check() -> Result<()> {
+    Ok(with_progress(Progress::check).unwrap_or(Ok(()))?)
+}
+
+// updates the progress bar and checks whether the solver should stop
+pub fn report(update: Update) -> Result<()> {
+    with_progress(|progress| {
+        if let Some(bar) = &progress.bar {
+            bar.set_length(update.rounds);
+            bar.set_position(update.round);
+            bar.set_message(match update.best {
+                Some(best) => format!("{} states, best {}", update.states, best),
+                None => format!("{} states", update.states),
+            });
+        }
+        tracing::trace!(round = update.round, rounds = update.rounds, states = update.states, best = update.best, "progress");
+    });
+    check()
+}
@@ -0,0 +1,218 @@
+// Python bindings, build the extension module with
+//     cargo rustc --release --lib --features python --crate-type cdylib
+//     cp target/release/liblibaoc.so python/libaoc.so
+// and run the smoke tests with `python3 -m unittest discover python`.
+// pyo3 0.22 macros trip this lint on every fallible #[pyfunction]
+#![allow(clippy::useless_conversion)]
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use pyo3::exceptions::{PyIndexError, PyKeyError, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyCFunction, PyDict, PyTuple};
+
+use crate::grid::{self, Position};
+use crate::registry::{self, Day};
+use crate::runner::Answer;
+use crate::{interval, search};
+
+// images come back as one string with a row per line, parts without a question as None
+fn to_python(py: Python, answer: &Answer) -> PyObject {
+    match answer {
+        Answer::Integer(n) => n.into_py(py),
+        Answer::Text(s) => s.into_py(py),
+        Answer::Image(_) => answer.to_string().into_py(py),
+        Answer::NotApplicable => py.None(),
+    }
+}
+
+fn solve_day(py: Python, day: &Day, input: &str, part: u32) -> PyResult<PyObject> {
+    let solver = day.part(part).ok_or_else(|| PyValueError::new_err(format!("part must be 1 or 2, got {}", part)))?;
+    let answer = py
+        .allow_threads(|| solver(input))
+        .map_err(|e| PyRuntimeError::new_err(format!("{:#}", e)))?;
+    Ok(to_python(py, &answer))
+}
+
+fn get_day(year: Option<u32>, day: u32) -> PyResult<&'static Day> {
+    let year = year.unwrap_or_else(registry::latest_year);
+    registry::get(year, day).ok_or_else(|| PyKeyError::new_err(format!("{} day {} is not registered", year, day)))
+}
+
+#[pyfunction]
+#[pyo3(signature = (day, input, part, year=None))]
+fn solve(py: Python, day: u32, input: &str, part: u32, year: Option<u32>) -> PyResult<PyObject> {
+    solve_day(py, get_day(year, day)?, input, part)
+}
+
+#[pyfunction]
+fn days() -> Vec<(u32, u32)> {
+    registry::all().map(|day| (day.year, day.day)).collect()
+}
+
+// a grid of characters, positions are (row, column)
+#[pyclass(module = "libaoc")]
+#[derive(Clone)]
+struct Grid(grid::Grid<char>);
+
+#[pymethods]
+impl Grid {
+    #[new]
+    fn new(text: &str) -> PyResult<Self> {
+        grid::Grid::parse(text, |c| c).map(Grid).map_err(|e| PyValueError::new_err(e.to_string()))
+    }
+
+    #[getter]
+    fn width(&self) -> usize {
+        self.0.width
+    }
+
+    #[getter]
+    fn height(&self) -> usize {
+        self.0.height
+    }
+
+    fn __getitem__(&self, pos: Position) -> PyResult<char> {
+        self.0.get(pos).copied().ok_or_else(|| PyIndexError::new_err(format!("{:?} is outside of the grid", pos)))
+    }
+
+    fn __setitem__(&mut self, pos: Position, c: char) -> PyResult<()> {
+        let cell = self.0.get_mut(pos).ok_or_else(|| PyIndexError::new_err(format!("{:?} is outside of the grid", pos)))?;
+        *cell = c;
+        Ok(())
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn find(&self, c: char) -> Option<Position> {
+        self.0.find(|&cell| cell == c)
+    }
+
+    fn find_all(&self, c: char) -> Vec<Position> {
+        self.0.iter().filter(|(_, &cell)| cell == c).map(|(pos, _)| pos).collect()
+    }
+
+    fn neighbours4(&self, pos: Position) -> Vec<Position> {
+        self.0.neighbours4(pos).collect()
+    }
+
+    fn neighbours8(&self, pos: Position) -> Vec<Position> {
+        self.0.neighbours8(pos).collect()
+    }
+
+    // distances from start to every cell reachable through cells that are not walls
+    #[pyo3(signature = (start, walls="#"))]
+    fn distances(&self, start: Position, walls: &str) -> HashMap<Position, usize> {
+        search::bfs_distances(start, |&pos| {
+            self.0.neighbours4(pos).filter(|&next| !walls.contains(self.0[next])).collect::<Vec<_>>()
+        })
+    }
+}
+
+#[pyfunction]
+fn merge_intervals(intervals: Vec<interval::Interval>) -> Vec<interval::Interval> {
+    interval::merge(intervals)
+}
+
+#[pyfunction]
+fn covered(intervals: Vec<interval::Interval>) -> u64 {
+    interval::covered(&interval::merge(intervals))
+}
+
+#[pyfunction]
+fn first_gap(intervals: Vec<interval::Interval>, bounds: interval::Interval) -> Option<i64> {
+    interval::first_gap(&interval::merge(intervals), bounds)
+}
+
+// python objects as search states, hashed and compared by python
+struct State(PyObject);
+
+impl Clone for State {
+    fn clone(&self) -> Self {
+        Python::with_gil(|py| State(self.0.clone_ref(py)))
+    }
+}
+
+impl PartialEq for State {
+    fn eq(&self, other: &Self) -> bool {
+        Python::with_gil(|py| self.0.bind(py).eq(other.0.bind(py)).unwrap_or(false))
+    }
+}
+
+impl Eq for State {}
+
+impl Hash for State {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Python::with_gil(|py| self.0.bind(py).hash().unwrap_or(0)).hash(state)
+    }
+}
+
+/// bfs(starts, neighbours, is_goal) -> (goal, distance) | None
+#[pyfunction]
+fn bfs(py: Python, starts: Vec<PyObject>, neighbours: PyObject, is_goal: PyObject) -> PyResult<Option<(PyObject, usize)>> {
+    // the first exception raised by a callback stops the search and is re-raised
+    let error = RefCell::new(None);
+    let fail = |e: PyErr| {
+        error.borrow_mut().get_or_insert(e);
+    };
+    let result = search::bfs(
+        starts.into_iter().map(State),
+        |state: &State| {
+            let next = neighbours.call1(py, (state.0.clone_ref(py),)).and_then(|r| r.extract::<Vec<PyObject>>(py));
+            next.unwrap_or_else(|e| {
+                fail(e);
+                Vec::new()
+            })
+            .into_iter()
+            .map(State)
+        },
+        |state| {
+            let goal = is_goal.call1(py, (state.0.clone_ref(py),)).and_then(|r| r.extract::<bool>(py));
+            goal.unwrap_or_else(|e| {
+                fail(e);
+                true
+            })
+        },
+    );
+    match error.into_inner() {
+        Some(e) => Err(e),
+        None => Ok(result.map(|(state, distance)| (state.0, distance))),
+    }
+}
+
+fn day_module<'py>(py: Python<'py>, day: &'static Day) -> PyResult<Bound<'py, PyModule>> {
+    let module = PyModule::new_bound(py, &format!("day{:02}", day.day))?;
+    let solve = PyCFunction::new_closure_bound(
+        py,
+        Some(c"solve"),
+        Some(c"solve(input, part)"),
+        move |args: &Bound<'_, PyTuple>, _kwargs: Option<&Bound<'_, PyDict>>| -> PyResult<PyObject> {
+            let (input, part): (String, u32) = args.extract()?;
+            solve_day(args.py(), day, &input, part)
+        },
+    )?;
+    module.add("solve", solve)?;
+    Ok(module)
+}
+
+#[pymodule]
+fn libaoc(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(solve, m)?)?;
+    m.add_function(wrap_pyfunction!(days, m)?)?;
+    m.add_class::<Grid>()?;
+    m.add_function(wrap_pyfunction!(merge_intervals, m)?)?;
+    m.add_function(wrap_pyfunction!(covered, m)?)?;
+    m.add_function(wrap_pyfunction!(first_gap, m)?)?;
+    m.add_function(wrap_pyfunction!(bfs, m)?)?;
+    for year in registry::YEARS {
+        let year_module = PyModule::new_bound(m.py(), &format!("y{}", year.year))?;
+        for day in year.days {
+            year_module.add_submodule(&day_module(m.py(), day)?)?;
+        }
+        m.add_submodule(&year_module)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use anyhow::Result;
+
+use crate::differential::Rng;
+use crate::repl::SessionFactory;
+use crate::runner::Answer;
+use crate::y2022;
+
+pub type Solver = fn(&str) -> Result<Answer>;
+
+// another implementation of a part, checked against the main one by `aoc diff`
+pub struct Alternative {
+    pub part: u32,
+    pub name: &'static str,
+    pub solver: Solver,
+}
+
+// a property of the input a solver relies on, `check` explains how an input breaks it
+pub struct Assumption {
+    pub name: &'static str,
+    pub check: fn(&str) -> Result<()>,
+}
+
+// text output of a day that is compared against golden files by `aoc snapshot`
+pub struct Render {
+    pub name: &'static str,
+    pub render: fn(&str) -> Result<String>,
+}
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub source: &'static str,
+    pub part01: Solver,
+    pub part02: Solver,
+    pub repl: Option<SessionFactory>,
+    pub alternatives: &'static [Alternative],
+    pub generate: Option<fn(&mut Rng) -> String>,
+    pub assumptions: &'static [Assumption],
+    pub renders: &'static [Render],
+}
+
+impl Day {
+    pub fn part(&self, part: u32) -> Option<Solver> {
+        match part {
+            1 => Some(self.part01),
+            2 => Some(self.part02),
+            _ => None,
+        }
+    }
+}
+
+pub struct Year {
+    pub year: u32,
+    pub days: &'static [Day],
+}
+
+// every entry point normalizes its input, so no day has to care how the file was saved
+macro_rules! solver {
+    ($f:path) => {
+        |input: &str| $f(&$crate::input::normalize(input)).map($crate::runner::IntoAnswer::into_answer)
+    };
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $module:ident) => {
+        $crate::registry::Day {
+            year: $year,
+            day: $day,
+            source: include_str!(concat!(stringify!($module), ".rs")),
+            part01: solver!($module::part01),
+            part02: solver!($module::part02),
+            repl: None,
+            alternatives: &[],
+            generate: None,
+            assumptions: &[],
+            renders: &[],
+        }
+    };
+}
+
+macro_rules! alternative {
+    ($part:literal, $name:literal, $f:path) => {
+        $crate::registry::Alternative { part: $part, name: $name, solver: solver!($f) }
+    };
+}
+
+macro_rules! assumption {
+    ($name:literal, $f:path) => {
+        $crate::registry::Assumption { name: $name, check: |input: &str| $f(&$crate::input::normalize(input)) }
+    };
+}
+
+macro_rules! render {
+    ($name:literal, $f:path) => {
+        $crate::registry::Render { name: $name, render: |input: &str| $f(&$crate::input::normalize(input)) }
+    };
+}
+
+pub static YEARS: &[Year] = &[
+    Year { year: 2022, days: y2022::DAYS },
+];
+
+pub fn year(year: u32) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+pub fn latest_year() -> u32 {
+    YEARS.iter().map(|y| y.year).max().unwrap()
+}
+
+pub fn get(year: u32, day: u32) -> Option<&'static Day> {
+    self::year(year)?.days.iter().find(|d| d.day == day)
+}
+
+pub fn all() -> impl Iterator<Item = &'static Day> {
+    YEARS.iter().flat_map(|y| y.days.iter())
+}
@@ -0,0 +1,62 @@
+use std::panic::{self, AssertUnwindSafe};
+
+use anyhow::Result;
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+
+use crate::registry::Day;
+use crate::runner;
+
+pub trait Session {
+    // (usage, description) pairs listed by `help`
+    fn commands(&self) -> &'static [(&'static str, &'static str)];
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String>;
+}
+
+pub type SessionFactory = fn(&str) -> Result<Box<dyn Session>>;
+
+const HISTORY: &str = "./target/aoc-repl-history";
+
+fn help(session: &dyn Session) -> String {
+    let mut lines = vec!["help".to_string(), "quit".to_string()];
+    let width = session.commands().iter().map(|(usage, _)| usage.len()).max().unwrap_or(0);
+    lines.extend(session.commands().iter().map(|(usage, description)| format!("{:width$}  {}", usage, description, width = width)));
+    lines.join("\n")
+}
+
+pub fn repl(day: &Day, input_name: &str) -> Result<()> {
+    let Some(factory) = day.repl else {
+        anyhow::bail!("{} day {} has no repl", day.year, day.day);
+    };
+    let input = std::fs::read_to_string(runner::input_path(day.year, day.day, input_name))?;
+    let mut session = factory(&crate::input::normalize(&input))?;
+    let mut editor = DefaultEditor::new()?;
+    let _ = editor.load_history(HISTORY);
+    let prompt = format!("{}/{:02} [{}]> ", day.year, day.day, input_name);
+    loop {
+        let line = match editor.readline(&prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) | Err(ReadlineError::Eof) => break,
+            Err(e) => return Err(e.into()),
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&command, args)) = words.split_first() else {
+            continue;
+        };
+        editor.add_history_entry(line.as_str())?;
+        match command {
+            "quit" | "exit" => break,
+            "help" => println!("{}", help(session.as_ref())),
+            // a command that panics ends the command, not the session
+            command => match panic::catch_unwind(AssertUnwindSafe(|| session.execute(command, args))) {
+                Ok(Ok(output)) => println!("{}", output),
+                Ok(Err(e)) => println!("error: {:#}", e),
+                Err(payload) => println!("error: panicked: {}", runner::panic_message(payload.as_ref())),
+            },
+        }
+    }
+    std::fs::create_dir_all("./target")?;
+    editor.save_history(HISTORY)?;
+    Ok(())
+}
@@ -0,0 +1,371 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::time::Instant;
+
+use anyhow::{anyhow, bail, Result};
+use serde::{Deserialize, Serialize};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::EnvFilter;
+
+use crate::cache::{self, Cache};
+use crate::context::{self, CheckpointOptions, Context};
+use crate::explain::Explanation;
+use crate::progress::{Progress, ProgressOptions, Stopped};
+use crate::registry::{self, Day};
+
+// what a part produces, every solver's return type converts into one
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    // a picture the puzzle wants read off, one string per row
+    Image(Vec<String>),
+    // the puzzle has no question for this part
+    NotApplicable,
+}
+
+impl Answer {
+    pub fn image(text: &str) -> Self {
+        Answer::Image(text.lines().map(str::to_string).collect())
+    }
+
+    // the single line form used by ledgers, images join their rows with `/`
+    pub fn to_line(&self) -> String {
+        match self {
+            Answer::Image(rows) => format!("image:{}", rows.join("/")),
+            Answer::NotApplicable => "n/a".to_string(),
+            answer => answer.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{}", n),
+            Answer::Text(s) => f.write_str(s),
+            Answer::Image(rows) => f.write_str(&rows.join("\n")),
+            Answer::NotApplicable => f.write_str("n/a"),
+        }
+    }
+}
+
+// parses the line form, anything that is not an integer, image or n/a is text
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "n/a" => Answer::NotApplicable,
+            _ if s.starts_with("image:") => Answer::Image(s["image:".len()..].split('/').map(str::to_string).collect()),
+            _ => s.parse().map_or_else(|_| Answer::Text(s.to_string()), Answer::Integer),
+        })
+    }
+}
+
+pub trait IntoAnswer {
+    fn into_answer(self) -> Answer;
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),*) => {
+        // answers past i64 do not occur, but keep their digits rather than wrap
+        $(impl IntoAnswer for $t {
+            fn into_answer(self) -> Answer {
+                i64::try_from(self).map_or_else(|_| Answer::Text(self.to_string()), Answer::Integer)
+            }
+        })*
+    };
+}
+
+impl_into_answer!(u32, u64, i32, i64, usize);
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> Answer {
+        Answer::Text(self)
+    }
+}
+
+impl IntoAnswer for Answer {
+    fn into_answer(self) -> Answer {
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => bail!("unknown format {:?}, expected text or json", s),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Error,
+    Panic,
+    MissingInput,
+    TimedOut,
+    Cancelled,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub answer: Option<Answer>,
+    pub time_ms: f64,
+    #[serde(default)]
+    pub cached: bool,
+    pub status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub explanation: Vec<String>,
+}
+
+impl Record {
+    fn failed(day: &Day, part: u32, input: &str, status: Status, error: String) -> Self {
+        Record { year: day.year, day: day.day, part, input: input.to_string(), answer: None, time_ms: 0.0, cached: false, status, error: Some(error), explanation: Vec::new() }
+    }
+
+    pub fn result(&self) -> String {
+        match self.status {
+            Status::Ok => self.answer.as_ref().map(Answer::to_string).unwrap_or_default(),
+            Status::TimedOut | Status::Cancelled => self.error.clone().unwrap_or_default(),
+            _ => format!("{:?}: {}", self.status, self.error.as_deref().unwrap_or_default()),
+        }
+    }
+
+    pub fn print(&self, format: Format) {
+        match format {
+            Format::Json => println!("{}", serde_json::to_string(self).unwrap()),
+            Format::Text => {
+                let cached = if self.cached { ", cached" } else { "" };
+                let label = format!("{} day{:02} part {} [{}]", self.year, self.day, self.part, self.input);
+                match &self.answer {
+                    // pictures go below the line, indented so they stay readable
+                    Some(Answer::Image(rows)) if self.status == Status::Ok => {
+                        println!("{} image ({:.3} ms{})", label, self.time_ms, cached);
+                        rows.iter().for_each(|row| println!("    {}", row));
+                    }
+                    _ => println!("{} {} ({:.3} ms{})", label, self.result(), self.time_ms, cached),
+                }
+                self.explanation.iter().for_each(|line| println!("    {}", line));
+            }
+        }
+    }
+}
+
+pub fn data_dir(year: u32) -> String {
+    format!("./data/{}", year)
+}
+
+pub fn input_path(year: u32, day: u32, input: &str) -> String {
+    format!("{}/day{:02}.{}", data_dir(year), day, input)
+}
+
+pub fn available_inputs(year: u32, day: u32) -> Result<Vec<String>> {
+    let prefix = format!("day{:02}.", day);
+    let mut inputs: Vec<String> = std::fs::read_dir(data_dir(year))?
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| name.strip_prefix(&prefix).map(str::to_string))
+        .filter(|input| input != "answers")
+        .collect();
+    inputs.sort();
+    Ok(inputs)
+}
+
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RunOptions {
+    pub checkpoints: Option<CheckpointOptions>,
+    pub progress: ProgressOptions,
+    pub explain: bool,
+}
+
+pub fn run_part(day: &Day, part: u32, input_name: &str, cache: Option<&Cache>, options: &RunOptions) -> Record {
+    if day.part(part).is_none() {
+        return Record::failed(day, part, input_name, Status::Error, format!("no part {}", part));
+    }
+    let input = match std::fs::read_to_string(input_path(day.year, day.day, input_name)) {
+        Ok(input) => input,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            return Record::failed(day, part, input_name, Status::MissingInput, e.to_string())
+        }
+        Err(e) => return Record::failed(day, part, input_name, Status::Error, e.to_string()),
+    };
+    // an explanation comes from actually solving, and should not be replayed from the cache later
+    let cache = cache.filter(|_| !options.explain);
+    if let Some(mut record) = cache.and_then(|cache| cache.get(day, part, &input)) {
+        record.input = input_name.to_string();
+        record.cached = true;
+        return record;
+    }
+    let record = solve(day, part, input_name, &input, options);
+    if let (Some(cache), Status::Ok) = (cache, record.status) {
+        if let Err(e) = cache.put(day, part, &input, &record) {
+            tracing::warn!("could not cache result: {:#}", e);
+        }
+    }
+    record
+}
+
+// runs a part on input that is already in memory, input_name only labels the record
+pub fn solve(day: &Day, part: u32, input_name: &str, input: &str, options: &RunOptions) -> Record {
+    let Some(solver) = day.part(part) else {
+        return Record::failed(day, part, input_name, Status::Error, format!("no part {}", part));
+    };
+    let _span = tracing::info_span!("part", year = day.year, day = day.day, part, input = input_name).entered();
+    let start = Instant::now();
+    let progress = Progress::start(&options.progress, format!("{} day{:02} part {}", day.year, day.day, part));
+    let context = Context {
+        year: day.year,
+        day: day.day,
+        part,
+        input_hash: cache::input_hash(input),
+        checkpoints: options.checkpoints.clone(),
+        progress: Some(progress.clone()),
+        explanation: options.explain.then(Explanation::default),
+    };
+    let explanation = context.explanation.clone();
+    let result = panic::catch_unwind(AssertUnwindSafe(|| context::scope(context, || solver(input))));
+    progress.finish();
+    let elapsed = start.elapsed();
+    let time_ms = (elapsed.as_secs_f64() * 1e6).round() / 1e3;
+    let mut record = match result {
+        Ok(Ok(answer)) => Record {
+            year: day.year,
+            day: day.day,
+            part,
+            input: input_name.to_string(),
+            answer: Some(answer),
+            time_ms,
+            cached: false,
+            status: Status::Ok,
+            error: None,
+            explanation: Vec::new(),
+        },
+        Ok(Err(e)) => match e.downcast_ref::<Stopped>() {
+            Some(stopped @ Stopped::TimedOut(_)) => Record::failed(day, part, input_name, Status::TimedOut, stopped.to_string()),
+            Some(stopped @ Stopped::Cancelled) => Record::failed(day, part, input_name, Status::Cancelled, stopped.to_string()),
+            None => Record::failed(day, part, input_name, Status::Error, format!("{:#}", e)),
+        },
+        Err(payload) => Record::failed(day, part, input_name, Status::Panic, panic_message(payload.as_ref())),
+    };
+    // solvers that never check the deadline still count as timed out when they finish late
+    if let Some(limit) = options.progress.time_limit.filter(|&limit| record.status == Status::Ok && elapsed > limit) {
+        record = Record::failed(day, part, input_name, Status::TimedOut, format!("finished after the {:?} limit", limit));
+    }
+    record.time_ms = time_ms;
+    // kept for failed parts too, the facts up to the failure are often the interesting ones
+    record.explanation = explanation.map(|explanation| explanation.lines()).unwrap_or_default();
+    tracing::info!(status = ?record.status, time_ms, "finished");
+    record
+}
+
+pub fn run_days(days: &[&Day], input_name: &str, format: Format, use_cache: bool, options: &RunOptions) -> Result<()> {
+    let cache = match use_cache {
+        true => Some(Cache::open()?),
+        false => None,
+    };
+    let mut failures = 0;
+    'days: for day in days {
+        for part in 1..=2 {
+            let record = run_part(day, part, input_name, cache.as_ref(), options);
+            if record.status != Status::Ok {
+                failures += 1;
+            }
+            record.print(format);
+            if options.progress.cancelled() {
+                break 'days;
+            }
+        }
+    }
+    match failures {
+        0 => Ok(()),
+        n => Err(anyhow!("{} part(s) did not produce an answer", n)),
+    }
+}
+
+pub fn init_tracing(verbosity: usize) {
+    tracing_subscriber::fmt()
+        .with_env_filter(env_filter(verbosity))
+        .with_writer(std::io::stderr)
+        .init();
+}
+
+fn env_filter(verbosity: usize) -> EnvFilter {
+    match EnvFilter::try_from_default_env() {
+        Ok(filter) => filter,
+        Err(_) => EnvFilter::new(match verbosity {
+            0 => "warn",
+            1 => "info",
+            2 => "debug",
+            _ => "trace",
+        }),
+    }
+}
+
+// for frontends that own the terminal and show the log themselves
+pub fn init_tracing_with_writer<W>(verbosity: usize, writer: W)
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    tracing_subscriber::fmt()
+        .with_env_filter(env_filter(verbosity))
+        .with_writer(writer)
+        .with_ansi(false)
+        .init();
+}
+
+pub fn verbosity(args: &mut Vec<String>) -> usize {
+    let mut verbosity = 0;
+    args.retain(|arg| match arg.as_str() {
+        "--verbose" => {
+            verbosity += 1;
+            false
+        }
+        _ if arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v') => {
+            verbosity += arg.len() - 1;
+            false
+        }
+        _ => true,
+    });
+    verbosity
+}
+
+pub fn run_bin(year: u32, day: u32) -> Result<()> {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    init_tracing(verbosity(&mut args));
+    let day = registry::get(year, day).ok_or_else(|| anyhow!("{} day {} is not registered", year, day))?;
+    let input = std::fs::read_to_string(input_path(year, day.day, "input"))?;
+    println!("{}", (day.part01)(&input)?);
+    println!("{}", (day.part02)(&input)?);
+    Ok(())
+}
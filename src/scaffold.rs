@@ -0,0 +1,68 @@
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::runner;
+
+const TEMPLATE: &str = include_str!("template.rs");
+
+fn insert_before_last(content: &str, marker: &str, line: &str) -> Result<String> {
+    let pos = content.rfind(marker).ok_or_else(|| anyhow!("could not find {:?}", marker))?;
+    Ok(format!("{}{}\n{}", &content[..pos], line, &content[pos..]))
+}
+
+fn insert_after_last(content: &str, prefix: &str, line: &str) -> Result<String> {
+    let start = content.rfind(prefix).ok_or_else(|| anyhow!("could not find {:?}", prefix))?;
+    let end = start + content[start..].find('\n').unwrap_or(content.len() - start) + 1;
+    Ok(format!("{}{}\n{}", &content[..end], line, &content[end..]))
+}
+
+fn edit(path: &str, f: impl FnOnce(&str) -> Result<String>) -> Result<()> {
+    let content = std::fs::read_to_string(path)?;
+    std::fs::write(path, f(&content)?)?;
+    Ok(())
+}
+
+fn register_year(year: u32) -> Result<()> {
+    let module_dir = format!("./src/y{}", year);
+    std::fs::create_dir_all(&module_dir)?;
+    std::fs::write(format!("{}/mod.rs", module_dir), "use crate::registry::Day;\n\npub static DAYS: &[Day] = &[\n];\n")?;
+    edit("./src/lib.rs", |content| insert_after_last(content, "pub mod y", &format!("pub mod y{};", year)))?;
+    edit("./src/registry.rs", |content| {
+        let content = insert_after_last(content, "use crate::y", &format!("use crate::y{};", year))?;
+        insert_before_last(&content, "];", &format!("    Year {{ year: {}, days: y{}::DAYS }},", year, year))
+    })?;
+    println!("registered year {}", year);
+    Ok(())
+}
+
+pub fn new_day(year: u32, day: u32) -> Result<()> {
+    if !(1..=25).contains(&day) {
+        bail!("day must be between 1 and 25");
+    }
+    let module_dir = format!("./src/y{}", year);
+    if !Path::new(&module_dir).exists() {
+        register_year(year)?;
+    }
+    let module = format!("{}/day{:02}.rs", module_dir, day);
+    if Path::new(&module).exists() {
+        bail!("{} already exists", module);
+    }
+    std::fs::write(&module, TEMPLATE)?;
+    edit(&format!("{}/mod.rs", module_dir), |content| {
+        let content = match content.contains("pub mod day") {
+            true => insert_after_last(content, "pub mod day", &format!("pub mod day{:02};", day))?,
+            false => content.replacen("\n\n", &format!("\n\npub mod day{:02};\n\n", day), 1),
+        };
+        insert_before_last(&content, "];", &format!("    day!({}, {}, day{:02}),", year, day, day))
+    })?;
+    println!("created {}", module);
+
+    std::fs::create_dir_all(runner::data_dir(year))?;
+    let example = runner::input_path(year, day, "example");
+    if !Path::new(&example).exists() {
+        std::fs::write(&example, "")?;
+        println!("created {}", example);
+    }
+    Ok(())
+}
@@ -0,0 +1,52 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+// breadth first search from all starts at once, returns the first goal state and its distance
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(S, usize)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if seen.insert(start.clone()) {
+            queue.push_back((start, 0));
+        }
+    }
+    while let Some((state, distance)) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some((state, distance));
+        }
+        for next in neighbours(&state) {
+            if seen.insert(next.clone()) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    None
+}
+
+// distance to every state reachable from start
+pub fn bfs_distances<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state];
+        for next in neighbours(&state) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
@@ -0,0 +1,120 @@
+use std::io::Read;
+use std::net::SocketAddr;
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response};
+
+use crate::registry::{self, Day};
+use crate::runner::{self, RunOptions};
+
+// inputs are a few KiB, anything much larger is a mistake
+const MAX_BODY: u64 = 16 << 20;
+
+// serves the registry over http, one request at a time
+pub struct Server {
+    http: tiny_http::Server,
+    options: RunOptions,
+}
+
+struct Reply {
+    status: u16,
+    body: Value,
+}
+
+impl Reply {
+    fn ok(body: Value) -> Self {
+        Reply { status: 200, body }
+    }
+
+    fn error(status: u16, message: impl ToString) -> Self {
+        Reply { status, body: json!({ "error": message.to_string() }) }
+    }
+}
+
+fn day_json(day: &Day) -> Value {
+    json!({
+        "year": day.year,
+        "day": day.day,
+        "parts": [1, 2],
+        "alternatives": day.alternatives.iter().map(|a| json!({ "part": a.part, "name": a.name })).collect::<Vec<_>>(),
+        "assumptions": day.assumptions.iter().map(|a| a.name).collect::<Vec<_>>(),
+        "renders": day.renders.iter().map(|r| r.name).collect::<Vec<_>>(),
+        "repl": day.repl.is_some(),
+    })
+}
+
+impl Server {
+    pub fn bind(addr: &str, options: RunOptions) -> Result<Self> {
+        let http = tiny_http::Server::http(addr).map_err(|e| anyhow!("could not listen on {}: {}", addr, e))?;
+        Ok(Server { http, options })
+    }
+
+    pub fn addr(&self) -> Option<SocketAddr> {
+        self.http.server_addr().to_ip()
+    }
+
+    // handles requests until `shutdown` is called
+    pub fn run(&self) {
+        for mut request in self.http.incoming_requests() {
+            let _span = tracing::info_span!("request", method = %request.method(), url = request.url()).entered();
+            let reply = self.handle(&mut request);
+            tracing::info!(status = reply.status, "reply");
+            let response = Response::from_string(reply.body.to_string())
+                .with_status_code(reply.status)
+                .with_header(Header::from_bytes("Content-Type", "application/json").unwrap());
+            if let Err(e) = request.respond(response) {
+                tracing::warn!("could not send reply: {}", e);
+            }
+        }
+    }
+
+    pub fn shutdown(&self) {
+        self.http.unblock();
+    }
+
+    fn handle(&self, request: &mut Request) -> Reply {
+        let url = request.url().to_string();
+        let (path, query) = url.split_once('?').unwrap_or((&url, ""));
+        let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+        let year = query.split('&').find_map(|pair| pair.strip_prefix("year="));
+        match (request.method().clone(), segments.as_slice()) {
+            (Method::Get, ["health"]) => Reply::ok(json!({ "status": "ok", "days": registry::all().count() })),
+            (Method::Get, ["days"]) => Reply::ok(Value::Array(registry::all().map(day_json).collect())),
+            (Method::Post, ["solve", day, part]) => self.solve(request, year, day, part),
+            (_, ["health"] | ["days"] | ["solve", _, _]) => Reply::error(405, "method not allowed"),
+            _ => Reply::error(404, format!("no route for {}", path)),
+        }
+    }
+
+    fn solve(&self, request: &mut Request, year: Option<&str>, day: &str, part: &str) -> Reply {
+        let year = match year.map(str::parse) {
+            None => registry::latest_year(),
+            Some(Ok(year)) => year,
+            Some(Err(_)) => return Reply::error(400, "year must be a number"),
+        };
+        let (Ok(day), Ok(part)) = (day.parse::<u32>(), part.parse::<u32>()) else {
+            return Reply::error(400, "day and part must be numbers");
+        };
+        let Some(day) = registry::get(year, day) else {
+            return Reply::error(404, format!("{} day {} is not registered", year, day));
+        };
+        if day.part(part).is_none() {
+            return Reply::error(404, format!("no part {}", part));
+        }
+        // one byte past the limit is enough to tell a body that is too large from one that fits
+        let mut body = Vec::new();
+        if let Err(e) = request.as_reader().take(MAX_BODY + 1).read_to_end(&mut body) {
+            return Reply::error(400, format!("could not read the input: {}", e));
+        }
+        if body.len() as u64 > MAX_BODY {
+            return Reply::error(413, format!("the input is larger than {} bytes", MAX_BODY));
+        }
+        let Ok(input) = String::from_utf8(body) else {
+            return Reply::error(400, "the input is not UTF-8");
+        };
+        // the record carries the status, a failing solver is still a successful request
+        let record = runner::solve(day, part, "request", &input, &self.options);
+        Reply::ok(serde_json::to_value(&record).unwrap())
+    }
+}
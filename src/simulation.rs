@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use anyhow::Result;
+
+use crate::progress;
+
+// something that advances in discrete steps, like falling sand or moving elves
+pub trait Simulation {
+    // identifies a state for cycle detection, `()` for simulations that never repeat
+    type Key: Hash + Eq;
+
+    // advances one step, returns false when nothing changed
+    fn step(&mut self) -> bool;
+
+    // None while the state cannot be compared yet, or when it never can
+    fn key(&self) -> Option<Self::Key> {
+        None
+    }
+
+    fn render(&self) -> String {
+        String::new()
+    }
+}
+
+// when `Driver::run` stops, `Steps` counts all steps the driver has taken
+pub enum Stop<'a, S> {
+    Steps(u64),
+    FixedPoint,
+    When(&'a dyn Fn(&S) -> bool),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    // the step after which the repeating state was first seen
+    pub start: u64,
+    pub length: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Steps,
+    FixedPoint,
+    Predicate,
+    // only with cycle detection, the driver stops at the first repeated key
+    Cycle(Cycle),
+}
+
+pub struct Driver<S: Simulation> {
+    pub simulation: S,
+    steps: u64,
+    // rendered state after every step, the initial state first
+    history: Option<Vec<String>>,
+    seen: Option<HashMap<S::Key, u64>>,
+}
+
+impl<S: Simulation> Driver<S> {
+    pub fn new(simulation: S) -> Self {
+        Driver { simulation, steps: 0, history: None, seen: None }
+    }
+
+    pub fn with_history(mut self) -> Self {
+        self.history = Some(vec![self.simulation.render()]);
+        self
+    }
+
+    // the current state counts as seen, a cycle can lead back to it
+    pub fn detect_cycles(mut self) -> Self {
+        let mut seen = HashMap::new();
+        if let Some(key) = self.simulation.key() {
+            seen.insert(key, self.steps);
+        }
+        self.seen = Some(seen);
+        self
+    }
+
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    pub fn history(&self) -> &[String] {
+        self.history.as_deref().unwrap_or_default()
+    }
+
+    // counts steps that were computed some other way, like whole cycles, and stops detecting cycles
+    pub fn skip(&mut self, steps: u64) {
+        self.steps += steps;
+        self.seen = None;
+    }
+
+    // one step with history and a cancellation check, returns whether anything changed, steps like
+    // a round of elves or a minute of blizzards take long enough that every one is checked
+    pub fn advance(&mut self) -> Result<bool> {
+        progress::check()?;
+        let changed = self.simulation.step();
+        self.steps += 1;
+        if let Some(history) = &mut self.history {
+            history.push(self.simulation.render());
+        }
+        Ok(changed)
+    }
+
+    // the cycle the current state closes, remembering the state otherwise
+    fn repeated(&mut self) -> Option<Cycle> {
+        let seen = self.seen.as_mut()?;
+        let key = self.simulation.key()?;
+        let start = *seen.entry(key).or_insert(self.steps);
+        (start != self.steps).then(|| Cycle { start, length: self.steps - start })
+    }
+
+    pub fn run(&mut self, stop: Stop<S>) -> Result<Outcome> {
+        loop {
+            match &stop {
+                Stop::Steps(n) if self.steps >= *n => return Ok(Outcome::Steps),
+                Stop::When(done) if done(&self.simulation) => return Ok(Outcome::Predicate),
+                _ => {}
+            }
+            if !self.advance()? {
+                return Ok(Outcome::FixedPoint);
+            }
+            if let Some(cycle) = self.repeated() {
+                tracing::debug!(start = cycle.start, length = cycle.length, "cycle found");
+                return Ok(Outcome::Cycle(cycle));
+            }
+        }
+    }
+}
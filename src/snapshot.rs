@@ -0,0 +1,179 @@
+use std::io::{BufRead, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+
+use crate::registry::{Day, Render};
+use crate::runner::{self, panic_message};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    // compare and leave a `.new` file next to every golden file that differs
+    Check,
+    // show every pending `.new` file and ask whether to accept it
+    Review,
+    // accept every pending `.new` file
+    Accept,
+}
+
+pub fn snapshot_dir(year: u32) -> PathBuf {
+    Path::new(&runner::data_dir(year)).join("snapshots")
+}
+
+pub fn golden_path(day: &Day, render: &Render, input: &str) -> PathBuf {
+    snapshot_dir(day.year).join(format!("day{:02}.{}.{}.txt", day.day, render.name, input))
+}
+
+fn pending_path(golden: &Path) -> PathBuf {
+    golden.with_extension("txt.new")
+}
+
+fn render_text(render: &Render, input: &str) -> Result<String, String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| (render.render)(input))) {
+        Ok(Ok(text)) => Ok(text.trim_end().to_string() + "\n"),
+        Ok(Err(e)) => Err(format!("{:#}", e)),
+        Err(payload) => Err(panic_message(payload.as_ref())),
+    }
+}
+
+// line by line, enough for grids that keep their shape
+fn print_diff(golden: &str, actual: &str) {
+    let (golden, actual): (Vec<&str>, Vec<&str>) = (golden.lines().collect(), actual.lines().collect());
+    for i in 0..golden.len().max(actual.len()) {
+        match (golden.get(i), actual.get(i)) {
+            (Some(g), Some(a)) if g == a => {}
+            (g, a) => {
+                if let Some(g) = g {
+                    println!("  {:>3} - {}", i + 1, g);
+                }
+                if let Some(a) = a {
+                    println!("  {:>3} + {}", i + 1, a);
+                }
+            }
+        }
+    }
+}
+
+// renders every input of the day and compares with the golden files, returns how many differ
+fn check_day(day: &Day, inputs: &[String]) -> Result<usize> {
+    let inputs = match inputs.is_empty() {
+        true => runner::available_inputs(day.year, day.day)?,
+        false => inputs.to_vec(),
+    };
+    let mut failures = 0;
+    for render in day.renders {
+        for input_name in &inputs {
+            let label = format!("{} day{:02} {} [{}]", day.year, day.day, render.name, input_name);
+            let Ok(input) = std::fs::read_to_string(runner::input_path(day.year, day.day, input_name)) else {
+                println!("{} skipped, input is not readable", label);
+                continue;
+            };
+            let actual = match render_text(render, &input) {
+                Ok(actual) => actual,
+                Err(e) => {
+                    failures += 1;
+                    println!("{} failed: {}", label, e);
+                    continue;
+                }
+            };
+            let golden_path = golden_path(day, render, input_name);
+            let pending = pending_path(&golden_path);
+            match std::fs::read_to_string(&golden_path) {
+                Ok(golden) if golden == actual => {
+                    println!("{} ok", label);
+                    if pending.exists() {
+                        std::fs::remove_file(&pending)?;
+                    }
+                    continue;
+                }
+                Ok(golden) => {
+                    println!("{} changed", label);
+                    print_diff(&golden, &actual);
+                }
+                Err(_) => println!("{} has no golden file yet", label),
+            }
+            failures += 1;
+            std::fs::create_dir_all(snapshot_dir(day.year))?;
+            std::fs::write(&pending, actual)?;
+        }
+    }
+    Ok(failures)
+}
+
+fn pending_files(day: &Day) -> Result<Vec<PathBuf>> {
+    let prefix = format!("day{:02}.", day.day);
+    let mut pending: Vec<PathBuf> = match std::fs::read_dir(snapshot_dir(day.year)) {
+        Ok(entries) => entries.filter_map(|entry| Some(entry.ok()?.path())).collect(),
+        Err(_) => Vec::new(),
+    };
+    pending.retain(|path| {
+        let name = path.file_name().and_then(|name| name.to_str()).unwrap_or_default();
+        name.starts_with(&prefix) && name.ends_with(".txt.new")
+    });
+    pending.sort();
+    Ok(pending)
+}
+
+fn accept(pending: &Path) -> Result<()> {
+    let golden = pending.with_extension("");
+    std::fs::rename(pending, &golden)?;
+    println!("accepted {}", golden.display());
+    Ok(())
+}
+
+fn review(pending: &Path, answers: &mut impl BufRead) -> Result<()> {
+    let golden = pending.with_extension("");
+    let actual = std::fs::read_to_string(pending)?;
+    match std::fs::read_to_string(&golden) {
+        Ok(golden_text) => {
+            println!("{}", golden.display());
+            print_diff(&golden_text, &actual);
+        }
+        Err(_) => println!("{} (new)\n{}", golden.display(), actual.trim_end()),
+    }
+    print!("accept? [y/N] ");
+    std::io::stdout().flush()?;
+    let mut answer = String::new();
+    answers.read_line(&mut answer)?;
+    match answer.trim() {
+        "y" | "yes" => accept(pending),
+        _ => {
+            println!("kept {}", pending.display());
+            Ok(())
+        }
+    }
+}
+
+pub fn snapshot(days: &[&Day], inputs: &[String], mode: Mode) -> Result<()> {
+    let days: Vec<&&Day> = days.iter().filter(|day| !day.renders.is_empty()).collect();
+    match mode {
+        Mode::Check => {
+            let hook = panic::take_hook();
+            panic::set_hook(Box::new(|_| {}));
+            let failures = days.iter().map(|day| check_day(day, inputs)).sum::<Result<usize>>();
+            panic::set_hook(hook);
+            match failures? {
+                0 => Ok(()),
+                n => Err(anyhow!("{} snapshot(s) differ from their golden file, see `aoc snapshot review`", n)),
+            }
+        }
+        Mode::Review => {
+            let mut stdin = std::io::stdin().lock();
+            for day in days {
+                for pending in pending_files(day)? {
+                    review(&pending, &mut stdin)?;
+                }
+            }
+            Ok(())
+        }
+        Mode::Accept => {
+            for day in days {
+                for pending in pending_files(day)? {
+                    accept(&pending)?;
+                }
+            }
+            Ok(())
+        }
+    }
+}
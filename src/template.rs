@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+pub fn part01(_input: &str) -> Result<u32> {
+    Ok(0)
+}
+
+pub fn part02(_input: &str) -> Result<u32> {
+    Ok(0)
+}
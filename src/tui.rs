@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+use anyhow::{anyhow, Result};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use tracing_subscriber::fmt::MakeWriter;
+
+use crate::cache::Cache;
+use crate::ledger::Ledger;
+use crate::registry::{self, Day};
+use crate::runner::{self, Answer, Record, RunOptions, Status};
+
+const KEYS: &str = "up/down day  1/2 part  i input  enter run  a run both  g render  q quit";
+const LOG_LINES: usize = 500;
+
+// tracing writes here instead of stderr while the tui owns the terminal
+#[derive(Debug, Clone, Default)]
+pub struct LogBuffer(Arc<Mutex<Vec<String>>>);
+
+impl LogBuffer {
+    fn tail(&self, n: usize) -> Vec<String> {
+        let lines = self.0.lock().unwrap();
+        lines[lines.len().saturating_sub(n)..].to_vec()
+    }
+}
+
+impl Write for LogBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut lines = self.0.lock().unwrap();
+        lines.extend(String::from_utf8_lossy(buf).lines().map(str::to_string));
+        let excess = lines.len().saturating_sub(LOG_LINES);
+        lines.drain(..excess);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for LogBuffer {
+    type Writer = LogBuffer;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+type RecordKey = (u32, u32, u32, String);
+
+struct App {
+    days: Vec<&'static Day>,
+    list: ListState,
+    part: u32,
+    input: String,
+    render: usize,
+    // this session's runs, or what the cache remembers from earlier ones
+    records: HashMap<RecordKey, Record>,
+    ledgers: HashMap<(u32, u32), Ledger>,
+    rendered: Option<(RecordKey, String)>,
+    cache: Option<Cache>,
+    options: RunOptions,
+    logs: LogBuffer,
+}
+
+impl App {
+    fn new(options: RunOptions, logs: LogBuffer) -> Self {
+        let days: Vec<&'static Day> = registry::all().collect();
+        let ledgers = days.iter().map(|day| ((day.year, day.day), Ledger::load(day.year, day.day).unwrap_or_default())).collect();
+        let mut app = App {
+            days,
+            list: ListState::default().with_selected(Some(0)),
+            part: 1,
+            input: "example".to_string(),
+            render: 0,
+            records: HashMap::new(),
+            ledgers,
+            rendered: None,
+            cache: Cache::open().ok(),
+            options,
+            logs,
+        };
+        app.load_cached();
+        app
+    }
+
+    fn day(&self) -> &'static Day {
+        self.days[self.list.selected().unwrap_or(0)]
+    }
+
+    fn key(&self, day: &Day, part: u32) -> RecordKey {
+        (day.year, day.day, part, self.input.clone())
+    }
+
+    fn read_input(&self, day: &Day) -> Option<String> {
+        std::fs::read_to_string(runner::input_path(day.year, day.day, &self.input)).ok()
+    }
+
+    // last timings for the current input name from the result cache
+    fn load_cached(&mut self) {
+        let Some(cache) = &self.cache else { return };
+        for day in &self.days {
+            let Ok(input) = std::fs::read_to_string(runner::input_path(day.year, day.day, &self.input)) else { continue };
+            for part in 1..=2 {
+                let key = (day.year, day.day, part, self.input.clone());
+                if let (false, Some(record)) = (self.records.contains_key(&key), cache.get(day, part, &input)) {
+                    self.records.insert(key, Record { cached: true, input: self.input.clone(), ..record });
+                }
+            }
+        }
+    }
+
+    fn next_input(&mut self) {
+        let inputs = runner::available_inputs(self.day().year, self.day().day).unwrap_or_default();
+        let next = inputs.iter().position(|input| *input == self.input).map_or(0, |i| (i + 1) % inputs.len().max(1));
+        if let Some(input) = inputs.get(next) {
+            self.input = input.clone();
+            self.load_cached();
+        }
+    }
+
+    fn run(&mut self, part: u32) {
+        let day = self.day();
+        let record = match self.read_input(day) {
+            Some(input) => {
+                let record = runner::solve(day, part, &self.input, &input, &self.options);
+                if let (Some(cache), Status::Ok) = (&self.cache, record.status) {
+                    if let Err(e) = cache.put(day, part, &input, &record) {
+                        tracing::warn!("could not cache result: {:#}", e);
+                    }
+                }
+                record
+            }
+            None => {
+                tracing::warn!(input = self.input, "input is missing or not readable");
+                return;
+            }
+        };
+        self.records.insert(self.key(day, part), record);
+    }
+
+    // ✓ agrees with the ledger, ✗ disagrees, ? no ledger entry, ! failed, · not run
+    fn mark(&self, day: &Day, part: u32) -> Span<'static> {
+        let expected = self.ledgers.get(&(day.year, day.day)).and_then(|ledger| ledger.get(&self.input, part));
+        match (self.records.get(&self.key(day, part)), expected) {
+            (None, _) => Span::raw("·"),
+            (Some(record), _) if record.status != Status::Ok => Span::styled("!", Style::new().fg(Color::Red)),
+            (Some(record), Some(expected)) if record.answer.as_ref() == Some(expected) => Span::styled("✓", Style::new().fg(Color::Green)),
+            (Some(_), Some(_)) => Span::styled("✗", Style::new().fg(Color::Red)),
+            (Some(_), None) => Span::styled("?", Style::new().fg(Color::Yellow)),
+        }
+    }
+
+    fn timing(&self, day: &Day, part: u32) -> String {
+        match self.records.get(&self.key(day, part)) {
+            Some(record) if record.status == Status::Ok => format!("{:>9.3}", record.time_ms),
+            _ => format!("{:>9}", "-"),
+        }
+    }
+
+    // image answers are shown as they are, otherwise the day's renderers draw the input
+    fn visual(&mut self) -> (String, String) {
+        let day = self.day();
+        if let Some(answer @ Answer::Image(_)) = self.records.get(&self.key(day, self.part)).and_then(|record| record.answer.as_ref()) {
+            return (format!("part {} answer", self.part), answer.to_string());
+        }
+        let Some(render) = day.renders.get(self.render % day.renders.len().max(1)) else {
+            return ("render".to_string(), "no renderings for this day".to_string());
+        };
+        let key = (day.year, day.day, self.render as u32, self.input.clone());
+        if self.rendered.as_ref().is_none_or(|(rendered, _)| *rendered != key) {
+            let text = match self.read_input(day).map(|input| (render.render)(&input)) {
+                Some(Ok(text)) => text,
+                Some(Err(e)) => format!("{:#}", e),
+                None => "input is missing or not readable".to_string(),
+            };
+            self.rendered = Some((key, text));
+        }
+        (render.name.to_string(), self.rendered.as_ref().unwrap().1.clone())
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [left, right] = Layout::horizontal([Constraint::Length(38), Constraint::Fill(1)]).areas(frame.area());
+        let [details, visual, logs] = Layout::vertical([Constraint::Length(8), Constraint::Fill(1), Constraint::Length(10)]).areas(right);
+        self.draw_days(frame, left);
+        self.draw_details(frame, details);
+        let (title, text) = self.visual();
+        frame.render_widget(Paragraph::new(text).block(Block::bordered().title(title)), visual);
+        let lines = self.logs.tail(logs.height.saturating_sub(2) as usize);
+        frame.render_widget(Paragraph::new(lines.join("\n")).block(Block::bordered().title("log")), logs);
+    }
+
+    fn draw_days(&mut self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .days
+            .iter()
+            .map(|day| {
+                ListItem::new(Line::from(vec![
+                    Span::raw(format!("{} day{:02} ", day.year, day.day)),
+                    self.mark(day, 1),
+                    self.mark(day, 2),
+                    Span::raw(format!(" {} {}", self.timing(day, 1), self.timing(day, 2))),
+                ]))
+            })
+            .collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(format!("days [{}] ms", self.input)))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, area, &mut self.list);
+    }
+
+    fn draw_details(&self, frame: &mut Frame, area: Rect) {
+        let day = self.day();
+        let expected = self.ledgers.get(&(day.year, day.day)).and_then(|ledger| ledger.get(&self.input, self.part));
+        let expected = expected.map_or("-".to_string(), Answer::to_line);
+        let mut lines = vec![Line::from(format!("{} day{:02} part {} on [{}]", day.year, day.day, self.part, self.input))];
+        match self.records.get(&self.key(day, self.part)) {
+            Some(record) => {
+                let answer = match record.answer {
+                    Some(Answer::Image(_)) => "(image below)".to_string(),
+                    _ => record.result(),
+                };
+                lines.push(Line::from(vec![Span::raw(format!("answer   {}  expected {} ", answer, expected)), self.mark(day, self.part)]));
+                lines.push(Line::from(format!("time     {:.3} ms{}", record.time_ms, if record.cached { " (cached)" } else { "" })));
+                lines.push(Line::from(format!("status   {:?}", record.status)));
+            }
+            None => lines.push(Line::from(format!("not run yet, expected {}", expected))),
+        }
+        lines.push(Line::from(Span::styled(KEYS, Style::new().fg(Color::DarkGray))));
+        frame.render_widget(Paragraph::new(lines).block(Block::bordered()), area);
+    }
+}
+
+fn event_loop(terminal: &mut DefaultTerminal, app: &mut App) -> Result<()> {
+    loop {
+        terminal.draw(|frame| app.draw(frame))?;
+        let Event::Key(key) = event::read()? else { continue };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Down | KeyCode::Char('j') => app.list.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => app.list.select_previous(),
+            KeyCode::Char('1') => app.part = 1,
+            KeyCode::Char('2') => app.part = 2,
+            KeyCode::Char('i') => app.next_input(),
+            KeyCode::Char('g') => app.render += 1,
+            KeyCode::Enter | KeyCode::Char('r') | KeyCode::Char('a') => {
+                let parts = if key.code == KeyCode::Char('a') { vec![1, 2] } else { vec![app.part] };
+                for part in parts {
+                    tracing::info!(day = app.day().day, part, input = app.input, "running");
+                    terminal.draw(|frame| app.draw(frame))?;
+                    app.run(part);
+                }
+                // solvers that print would have scribbled over the screen
+                terminal.clear()?;
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn tui(options: RunOptions, logs: LogBuffer) -> Result<()> {
+    let mut app = App::new(options, logs);
+    let mut terminal = ratatui::try_init().map_err(|e| anyhow!("could not set up the terminal: {}", e))?;
+    let result = event_loop(&mut terminal, &mut app);
+    ratatui::restore();
+    result
+}
@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use colored::Colorize;
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use crate::ledger::Ledger;
+use crate::runner::{self, Record, Status};
+
+pub struct WatchOptions {
+    pub year: u32,
+    pub day: u32,
+    pub inputs: Vec<String>,
+    pub release: bool,
+}
+
+fn is_relevant(path: &Path, year: u32, day: u32) -> bool {
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let parent = path.parent().and_then(|parent| parent.file_name()).and_then(|parent| parent.to_str());
+    let year_dir = year.to_string();
+    let module_dir = format!("y{}", year);
+    match parent {
+        Some(parent) if parent == year_dir => name.starts_with(&format!("day{:02}.", day)),
+        Some(parent) if parent == module_dir || parent == "bin" => name == format!("day{:02}.rs", day) || name == "mod.rs",
+        _ => name.ends_with(".rs") || name == "Cargo.toml",
+    }
+}
+
+fn build(release: bool) -> Result<bool> {
+    let mut cargo = Command::new("cargo");
+    cargo.args(["build", "--quiet", "--bin", "aoc"]);
+    if release {
+        cargo.arg("--release");
+    }
+    Ok(cargo.status()?.success())
+}
+
+fn run_input(options: &WatchOptions, input: &str) -> Result<Vec<Record>> {
+    let binary = match options.release {
+        true => "./target/release/aoc",
+        false => "./target/debug/aoc",
+    };
+    let output = Command::new(binary)
+        .args(["run", &options.year.to_string(), &options.day.to_string(), "--input", input, "--format", "json", "--no-cache"])
+        .output()?;
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+fn report(record: &Record, previous: Option<&String>, ledger: &Ledger) {
+    let result = record.result();
+    let mut line = format!("part {} [{}] {} ({:.3} ms)", record.part, record.input, result, record.time_ms);
+    if record.status != Status::Ok {
+        line = line.red().to_string();
+    }
+    match ledger.get(&record.input, record.part) {
+        Some(expected) if record.answer.as_ref() == Some(expected) => line += &format!(" {}", "ledger ok".green()),
+        Some(expected) => line += &format!(" {}", format!("ledger expects {}", expected.to_line()).red()),
+        None => (),
+    }
+    match previous {
+        Some(previous) if *previous != result => line += &format!(" {}", format!("(was {})", previous).yellow()),
+        _ => (),
+    }
+    println!("{}", line);
+}
+
+fn rerun(options: &WatchOptions, previous: &mut HashMap<(String, u32), String>) -> Result<()> {
+    println!("{}", format!("== {} day{:02} rebuilding", options.year, options.day).bold());
+    let start = Instant::now();
+    if !build(options.release)? {
+        println!("{}", "build failed".red());
+        return Ok(());
+    }
+    println!("built in {:.1}s", start.elapsed().as_secs_f64());
+    let ledger = Ledger::load(options.year, options.day)?;
+    let inputs = match options.inputs.is_empty() {
+        true => runner::available_inputs(options.year, options.day)?,
+        false => options.inputs.clone(),
+    };
+    for input in inputs {
+        for record in run_input(options, &input)? {
+            let key = (record.input.clone(), record.part);
+            report(&record, previous.get(&key), &ledger);
+            previous.insert(key, record.result());
+        }
+    }
+    Ok(())
+}
+
+pub fn watch(options: WatchOptions) -> Result<()> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(tx)?;
+    watcher.watch(Path::new("./src"), RecursiveMode::Recursive)?;
+    watcher.watch(Path::new(&runner::data_dir(options.year)), RecursiveMode::NonRecursive)?;
+    watcher.watch(Path::new("./Cargo.toml"), RecursiveMode::NonRecursive)?;
+
+    let mut previous = HashMap::new();
+    rerun(&options, &mut previous)?;
+    loop {
+        let mut changed = false;
+        let mut next = rx.recv()?;
+        loop {
+            let event = next?;
+            if !matches!(event.kind, EventKind::Access(_)) {
+                changed |= event.paths.iter().any(|path| is_relevant(path, options.year, options.day));
+            }
+            // editors tend to emit a burst of events per save
+            match rx.recv_timeout(Duration::from_millis(200)) {
+                Ok(event) => next = event,
+                Err(_) => break,
+            }
+        }
+        if changed {
+            rerun(&options, &mut previous)?;
+        }
+    }
+}
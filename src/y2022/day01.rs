@@ -0,0 +1,36 @@
+use anyhow::Result;
+
+use crate::explain;
+
+// elf number and calories, most calories first
+fn calories_per_elf(input: &str) -> Vec<(usize, i32)> {
+    let mut sums = input
+        .trim()
+        .split("\n\n")
+        .map(|block| block.split('\n').map(|item| item.parse::<i32>().unwrap()).sum())
+        .enumerate()
+        .map(|(elf, calories)| (elf + 1, calories))
+        .collect::<Vec<(usize, i32)>>();
+    sums.sort_unstable_by_key(|&(_, calories)| std::cmp::Reverse(calories));
+    sums
+}
+
+fn most_calories(input: &str, elves: usize) -> i32 {
+    calories_per_elf(input)
+        .iter()
+        .take(elves)
+        .enumerate()
+        .map(|(rank, &(elf, calories))| {
+            explain::line(|| format!("#{} is elf {} with {} calories", rank + 1, elf, calories));
+            calories
+        })
+        .sum::<i32>()
+}
+
+pub fn part01(input: &str) -> Result<i32> {
+    Ok(most_calories(input, 1))
+}
+
+pub fn part02(input: &str) -> Result<i32> {
+    Ok(most_calories(input, 3))
+}
@@ -0,0 +1,19 @@
+use anyhow::Result;
+
+pub fn part01(input: &str) -> Result<i32> {
+    Ok(input
+        .trim()
+        .split('\n')
+        .map(|block| block.as_bytes())
+        .map(|x| ((x[2] - x[0] + 2) % 3 * 3 + x[2] - 87) as i32)
+        .sum::<i32>())
+}
+
+pub fn part02(input: &str) -> Result<i32> {
+    Ok(input
+        .trim()
+        .split('\n')
+        .map(|block| block.as_bytes())
+        .map(|x| ((x[2] - 88) * 3 + (x[2] + x[0] + 2) % 3 + 1) as i32)
+        .sum::<i32>())
+}
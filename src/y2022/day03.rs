@@ -0,0 +1,40 @@
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::iter::FromIterator;
+
+pub fn part01(input: &str) -> Result<u32> {
+    Ok(input
+        .trim()
+        .split("\n")
+        .flat_map(|block| block.as_bytes().chunks(block.len() / 2))
+        .map(|block| -> HashSet<&u8> {HashSet::from_iter(block.iter())})
+        .chunks(2)
+        .into_iter()
+        .map(|mut sets| {
+            let intersection: &u8 = sets.next()
+                .map(|set: HashSet<&u8, _>| sets.fold(set, |set1: HashSet<&u8, _>, set2: HashSet<&u8, _>| &set1 & &set2)).unwrap().iter().next().unwrap();
+            ((intersection.to_ascii_lowercase() - 96) + (26 * intersection.is_ascii_uppercase() as u8)) as u32
+        })
+        .collect_vec()
+        .into_iter()
+        .sum())
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    Ok(input
+        .trim()
+        .split("\n")
+        .map(|block| block.as_bytes())
+        .map(|block| -> HashSet<&u8> {HashSet::from_iter(block.iter())})
+        .chunks(3)
+        .into_iter()
+        .map(|mut sets| {
+            let intersection: &u8 = sets.next()
+                .map(|set: HashSet<&u8, _>| sets.fold(set, |set1: HashSet<&u8, _>, set2: HashSet<&u8, _>| &set1 & &set2)).unwrap().iter().next().unwrap();
+            ((intersection.to_ascii_lowercase() - 96) + (26 * intersection.is_ascii_uppercase() as u8)) as u32
+        })
+        .collect_vec()
+        .into_iter()
+        .sum::<u32>())
+}
@@ -0,0 +1,37 @@
+use anyhow::Result;
+use itertools::Itertools;
+
+pub fn part01(input: &str) -> Result<u32> {
+    Ok(input
+        .trim()
+        .split(|c: char| !c.is_numeric())
+        .map(|s| s.parse::<u32>().unwrap())
+        .chunks(4)
+        .into_iter()
+        .map(|chunk| {
+            let bounds: Vec<u32> = chunk.collect();
+            (bounds[0] <= bounds[2] && bounds[1] >= bounds[3] ||
+            bounds[2] <= bounds[0] && bounds[3] >= bounds[1]) as u32
+
+        })
+        .collect_vec()
+        .into_iter()
+        .sum())
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    Ok(input
+        .trim()
+        .split(|c: char| !c.is_numeric())
+        .map(|s| s.parse::<u32>().unwrap())
+        .chunks(4)
+        .into_iter()
+        .map(|chunk| {
+            let bounds: Vec<u32> = chunk.collect();
+            (bounds[0] <= bounds[3] && bounds[1] >= bounds[2]) as u32
+
+        })
+        .collect_vec()
+        .into_iter()
+        .sum())
+}
@@ -0,0 +1,80 @@
+use anyhow::{bail, Context, Result};
+
+// the stack count is read from the last character of the drawing, so it must be a single digit
+pub fn check_single_digit_stacks(input: &str) -> Result<()> {
+    let (drawing, _) = input.split_once("\n\n").context("no blank line between the drawing and the moves")?;
+    let labels: Vec<&str> = drawing.lines().last().unwrap_or_default().split_whitespace().collect();
+    if labels.iter().zip(1..).any(|(label, i)| *label != i.to_string()) {
+        bail!("stack labels {:?} are not numbered 1 to {}", labels, labels.len());
+    }
+    if labels.len() > 9 {
+        bail!("{} stacks, but only a single digit stack count is read", labels.len());
+    }
+    if !drawing.ends_with(labels.last().copied().unwrap_or("1")) {
+        bail!("the label line does not end with the last stack number");
+    }
+    Ok(())
+}
+
+pub fn part01(input: &str) -> Result<String> {
+    let (drawing, moves) = input.split_once("\n\n").unwrap();
+    let number_of_piles = drawing.chars().last().unwrap().to_digit(10).unwrap() as usize;
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); number_of_piles];
+    let last_line = drawing.lines().last().unwrap();
+    let number_of_lines = drawing.lines().count();
+    for s in 1..number_of_piles+1 {
+        let pos = last_line.find(&s.clone().to_string()).unwrap();
+        for i in (0..number_of_lines-1).rev() {
+            let container = drawing.lines().nth(i).unwrap().chars().nth(pos);
+            match container {
+                Some(element) if element != ' ' => stacks[s-1].push(element),
+                Some(_) => (),
+                None => (),
+            }
+        }
+    }
+    moves
+        .trim()
+        .split("\n")
+        .map(|line| line.split_ascii_whitespace().filter_map(|word| word.parse().ok()).collect::<Vec<usize>>())
+        .for_each(|line| for _ in 0..line[0] {
+            let element = stacks[line[1]-1].pop().unwrap();
+            stacks[line[2]-1].push(element);
+        });
+    Ok(stacks
+       .into_iter()
+       .map(|mut stack| stack.pop().unwrap())
+       .collect::<String>())
+}
+
+pub fn part02(input: &str) -> Result<String> {
+    let (drawing, moves) = input.split_once("\n\n").unwrap();
+    let number_of_piles = drawing.chars().last().unwrap().to_digit(10).unwrap() as usize;
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); number_of_piles];
+    let last_line = drawing.lines().last().unwrap();
+    let number_of_lines = drawing.lines().count();
+    for s in 1..number_of_piles+1 {
+        let pos = last_line.find(&s.clone().to_string()).unwrap();
+        for i in (0..number_of_lines-1).rev() {
+            let container = drawing.lines().nth(i).unwrap().chars().nth(pos);
+            match container {
+                Some(element) if element != ' ' => stacks[s-1].push(element),
+                Some(_) => (),
+                None => (),
+            }
+        }
+    }
+    moves
+        .trim()
+        .split("\n")
+        .map(|line| line.split_ascii_whitespace().filter_map(|word| word.parse().ok()).collect::<Vec<usize>>())
+        .for_each(|line| {
+            let split_index = stacks[line[1]-1].len() - line[0];
+            let elements = stacks[line[1]-1].split_off(split_index);
+            stacks[line[2]-1].extend(elements);
+        });
+    Ok(stacks
+       .into_iter()
+       .map(|mut stack| stack.pop().unwrap())
+       .collect::<String>())
+}
@@ -0,0 +1,22 @@
+use anyhow::Result;
+use std::collections::HashSet;
+
+pub fn part01(input: &str) -> Result<u32> {
+    for i in 4..input.len() {
+        let mut set: HashSet<char> = HashSet::new();
+        if input[i-4..i].chars().all(move |x| set.insert(x)) {
+            return Ok(i as u32)
+        }
+    }
+    Ok(input.len() as u32)
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    for i in 14..input.len() {
+        let mut set: HashSet<char> = HashSet::new();
+        if input[i-14..i].chars().all(move |x| set.insert(x)) {
+            return Ok(i as u32)
+        }
+    }
+    Ok(input.len() as u32)
+}
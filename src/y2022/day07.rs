@@ -0,0 +1,84 @@
+use anyhow::Result;
+use itertools::Itertools;
+
+use crate::explain;
+
+// the directory the `cd`s in path lead to, path[0] is the root
+fn dir_name(path: &[&str]) -> String {
+    format!("/{}", path.iter().skip(1).join("/"))
+}
+
+pub fn part01(input: &str) -> Result<u32> {
+    let lines: Vec<&str> = input.lines()
+        .collect();
+    let mut sums = vec![0; 1];
+    let mut path = Vec::new();
+    let mut result = 0;
+    let mut depth = 0;
+    for line in lines {
+        match line {
+            _ if line == "$ cd .." => {
+                if sums[depth] < 100000 {
+                    explain::line(|| format!("{} holds {}, at most 100000", dir_name(&path), sums[depth]));
+                    result += sums[depth];
+                }
+                sums[depth - 1] += sums[depth];
+                depth -= 1;
+                sums.pop();
+                path.pop();
+            },
+            _ if line.starts_with("$ cd") => {
+                sums.push(0);
+                path.push(&line[5..]);
+                depth += 1;
+            },
+            _ if sums[depth] > 100000 => (),
+            _ if line.starts_with("dir") => (),
+            _ if line.starts_with("$ ls") => (),
+            file => sums[depth] += file.split_whitespace().next().unwrap().parse::<u32>().unwrap(),
+        }
+    }
+    Ok(result)
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    let lines: Vec<&str> = input.lines()
+        .collect();
+    let mut sums = vec![0; 1];
+    let mut path = vec!["/"];
+    let mut dir_sizes = Vec::new();
+    let mut depth = 0;
+    // the first line is `$ cd /`, the root is sums[0]
+    for &line in lines[1..].iter() {
+        match line.to_string() {
+            _ if line == "$ cd .." => {
+                sums[depth - 1] += sums[depth];
+                dir_sizes.push((sums[depth], dir_name(&path)));
+                depth -= 1;
+                sums.pop();
+                path.pop();
+            },
+            _ if line.starts_with("$ cd") => {
+                sums.push(0);
+                path.push(&line[5..]);
+                depth += 1;
+            },
+            _ if line.starts_with("$ ls") => (),
+            _ if line.starts_with("dir") => (),
+            file => sums[depth] += file.split_whitespace().next().unwrap().parse::<u32>().unwrap(),
+        }
+    }
+    // ugly directory size reconstruction
+    dir_sizes.extend((1..sums.len()).map(|depth| (sums[depth], dir_name(&path[..=depth]))));
+    let total_size: u32 = sums.into_iter().sum();
+    dir_sizes.push((total_size, "/".to_string()));
+    dir_sizes.sort();
+    explain::line(|| format!("{} used, at most 40000000 may be", total_size));
+    for (sum, dir) in dir_sizes {
+        if total_size - sum < 40000000 {
+            explain::line(|| format!("deleting {} frees {}, the smallest that is enough", dir, sum));
+            return Ok(sum)
+        }
+    }
+    Ok(0)
+}
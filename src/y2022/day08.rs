@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use anyhow::{bail, Result};
+
+use crate::parallel;
+
+// both parts use the row count as the width too
+pub fn check_square_grid(input: &str) -> Result<()> {
+    let lines: Vec<&str> = input.trim().split('\n').collect();
+    if let Some((i, line)) = lines.iter().enumerate().find(|(_, line)| line.len() != lines.len()) {
+        bail!("line {} has {} trees, but there are {} lines", i + 1, line.len(), lines.len());
+    }
+    if let Some(c) = input.trim().chars().find(|c| !c.is_ascii_digit() && *c != '\n') {
+        bail!("{:?} is not a tree height", c);
+    }
+    Ok(())
+}
+
+#[allow(clippy::needless_range_loop)]
+pub fn part01(input: &str) -> Result<u32> {
+    let matrix: Vec<Vec<u32>> = input
+        .trim()
+        .split("\n")
+        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect();
+    let mut visible_trees: HashSet<(usize, usize)> = HashSet::new();
+    let dim = matrix.len();
+    for i in 1..dim-1 {
+        let mut prefix_max = matrix[i][0];
+        for j in 1..dim-1 {
+            let item = matrix[i][j];
+            if item > prefix_max {
+                visible_trees.insert((i, j));
+                prefix_max = item;
+            }
+        }
+        prefix_max = matrix[i][dim-1];
+        for j in (1..dim-1).rev() {
+            let item = matrix[i][j];
+            if item > prefix_max {
+                visible_trees.insert((i, j));
+                prefix_max = item;
+            }
+        }
+    }
+
+    for i in 1..dim-1 {
+        let mut prefix_max = matrix[0][i];
+        for j in 1..dim-1 {
+            let item = matrix[j][i];
+            if item > prefix_max {
+                visible_trees.insert((j, i));
+                prefix_max = item;
+            }
+        }
+        prefix_max = matrix[dim-1][i];
+        for j in (1..dim-1).rev() {
+            let item = matrix[j][i];
+            if item > prefix_max {
+                visible_trees.insert((j, i));
+                prefix_max = item;
+            }
+        }
+    }
+    Ok((visible_trees.len() + 4 * (dim-1)) as u32)
+}
+
+// how far the tree at (i, j) sees in each direction, multiplied
+fn scenic_score(matrix: &[Vec<u32>], i: usize, j: usize) -> usize {
+    let dim = matrix.len();
+    let item = matrix[i][j];
+    let mut score = 1;
+    let (mut k, mut l) = (i-1, j-1);
+    while k > 0  {
+        if matrix[k][j] >= item {
+            break;
+        }
+        k -= 1;
+    }
+    score *= i - k;
+
+    k = i+1;
+    while k < dim-1  {
+        if matrix[k][j] >= item {
+            break;
+        }
+        k += 1;
+    }
+    score *= k - i;
+
+    while l > 0  {
+        if matrix[i][l] >= item {
+            break;
+        }
+        l -= 1;
+    }
+    score *= j - l;
+
+    l = j+1;
+    while l < dim-1  {
+        if matrix[i][l] >= item {
+            break;
+        }
+        l += 1;
+    }
+    score *= l - j;
+    score
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    let matrix: Vec<Vec<u32>> = input
+        .trim()
+        .split("\n")
+        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect();
+    let dim = matrix.len();
+    // trees on the edge see nothing in one direction and score 0
+    let best = parallel::max(1..dim.saturating_sub(1), |i| (1..dim-1).map(|j| scenic_score(&matrix, i, j)).max().unwrap_or(0));
+    Ok(best.unwrap_or(0) as u32)
+}
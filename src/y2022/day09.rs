@@ -0,0 +1,67 @@
+use anyhow::Result;
+use std::collections::HashSet;
+
+use crate::simulation::{Driver, Simulation, Stop};
+
+// the head moves one square per step, every knot follows the one before it
+struct Rope {
+    knots: Vec<(i32, i32)>,
+    moves: Vec<(i32, i32)>,
+    next_move: usize,
+    visited_positions: HashSet<(i32, i32)>,
+}
+
+impl Rope {
+    fn new(input: &str, num_knots: usize) -> Self {
+        let moves = input
+            .trim()
+            .split("\n")
+            .map(|line| line.split_once(" ").map(|(direction, step)| (direction, step.parse::<usize>().unwrap())).unwrap())
+            .flat_map(|(direction, step)| {
+                let delta = match direction {
+                    "U" => (1, 0),
+                    "D" => (-1, 0),
+                    "R" => (0, 1),
+                    "L" => (0, -1),
+                    _ => (0, 0)
+                };
+                std::iter::repeat_n(delta, step)
+            })
+            .collect();
+        let knots = vec![(0, 0); num_knots];
+        Rope { visited_positions: HashSet::from([knots[num_knots - 1]]), knots, moves, next_move: 0 }
+    }
+}
+
+impl Simulation for Rope {
+    type Key = ();
+
+    fn step(&mut self) -> bool {
+        let Some(&(dx, dy)) = self.moves.get(self.next_move) else { return false };
+        self.next_move += 1;
+        self.knots[0] = (self.knots[0].0 + dx, self.knots[0].1 + dy);
+        for i in 1..self.knots.len() {
+            let (head, tail) = (self.knots[i-1], self.knots[i]);
+            if head.0.abs_diff(tail.0) > 1 || head.1.abs_diff(tail.1) > 1 {
+                self.knots[i].0 += (head.0 - tail.0).clamp(-1, 1);
+                self.knots[i].1 += (head.1 - tail.1).clamp(-1, 1);
+            }
+        }
+        self.visited_positions.insert(self.knots[self.knots.len() - 1]);
+        true
+    }
+}
+
+fn visited_by_tail(input: &str, num_knots: usize) -> Result<u32> {
+    let mut driver = Driver::new(Rope::new(input, num_knots));
+    driver.run(Stop::FixedPoint)?;
+    Ok(driver.simulation.visited_positions.len() as u32)
+}
+
+pub fn part01(input: &str) -> Result<u32> {
+    visited_by_tail(input, 2)
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    visited_by_tail(input, 10)
+}
@@ -0,0 +1,72 @@
+use anyhow::Result;
+
+use crate::runner::Answer;
+
+pub fn part01(input: &str) -> Result<i32> {
+    let mut num_cycles = 0;
+    let mut sum_of_signal_strength = 0;
+    let mut x = 1;
+    input
+        .trim()
+        .lines()
+        .for_each(|line| match line.split_once(" ") {
+            Some((_, number)) => {
+                for _ in 0..2 {
+                    num_cycles += 1;
+                    if (num_cycles - 20) % 40 == 0 {
+                        sum_of_signal_strength += num_cycles * x;
+                    }
+                }
+                x += number.parse::<i32>().unwrap();
+            },
+            None => {
+                num_cycles += 1;
+                if (num_cycles - 20) % 40 == 0 {
+                    sum_of_signal_strength += num_cycles * x;
+                }
+            }
+        });
+    Ok(sum_of_signal_strength)
+}
+
+pub fn render_crt(input: &str) -> Result<String> {
+    let mut num_cycles = 0;
+    let mut ctr: Vec<Vec<char>> = vec![vec!['.'; 40]; 6];
+    let mut x = 1;
+    let mut row = -1;
+    input
+        .trim()
+        .lines()
+        .for_each(|line| match line.split_once(" ") {
+            Some((_, number)) => {
+                for _ in 0..2 {
+                    num_cycles += 1;
+                    if num_cycles % 40 == 1 {
+                        row += 1;
+                    }
+                    let ctr_col = (num_cycles - 1) % 40;
+                    tracing::trace!(num_cycles, x, ctr_col, "cycle");
+                    if x == ctr_col || x - 1 == ctr_col || x + 1 == ctr_col {
+                        ctr[row as usize][ctr_col as usize] = '#';
+                    }
+                }
+                x += number.parse::<i32>().unwrap();
+            },
+            None => {
+                num_cycles += 1;
+                if num_cycles % 40 == 1 {
+                    row += 1;
+                }
+                let ctr_col = (num_cycles - 1) % 40;
+                tracing::trace!(num_cycles, x, ctr_col, "cycle");
+                if x == ctr_col || x - 1 == ctr_col || x + 1 == ctr_col {
+                    ctr[row as usize][ctr_col as usize] = '#';
+                }
+            }
+        });
+    Ok(ctr.into_iter().map(|line| line.into_iter().collect::<String>()).collect::<Vec<_>>().join("\n"))
+}
+
+pub fn part02(input: &str) -> Result<Answer> {
+    Ok(Answer::image(&render_crt(input)?))
+}
@@ -0,0 +1,135 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::checked;
+use crate::checkpoint;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Monkey {
+    items: Vec<u32>,
+    div: u32,
+    true_target: usize,
+    false_target: usize,
+    operand: char,
+    operators: (String, String)
+}
+
+impl Monkey {
+    fn decide_target(&self, item: u32) -> Result<(usize, u32)> {
+        let parsed_operators = match (self.operators.0.as_str(), self.operators.1.as_str()) {
+            ("old", "old") => (item, item),
+            ("old", num) => (item, num.parse::<u32>().unwrap()),
+            _ => (0, 0)
+        };
+        let new_worry_level = match self.operand {
+            '+' => checked::add(parsed_operators.0, parsed_operators.1)?,
+            '*' => checked::mul(parsed_operators.0, parsed_operators.1)?,
+            _ => 0
+        } / 3;
+        Ok(match new_worry_level % self.div {
+            0 => (self.true_target, new_worry_level),
+            _ => (self.false_target, new_worry_level)
+        })
+    }
+
+    fn decide_target_2(&self, item: u32, worry_mod: u32) -> (usize, u32) {
+        let parsed_operators = match (self.operators.0.as_str(), self.operators.1.as_str()) {
+            ("old", "old") => (item % worry_mod, item % worry_mod),
+            ("old", num) => (item % worry_mod, num.parse::<u32>().unwrap()),
+            _ => (0, 0)
+        };
+        let new_worry_level: u64 = match self.operand {
+            '+' => parsed_operators.0 as u64 + parsed_operators.1 as u64,
+            '*' => parsed_operators.0 as u64 * parsed_operators.1 as u64,
+            _ => 0
+        } % worry_mod as u64;
+        match new_worry_level % self.div as u64 {
+            0 => (self.true_target, new_worry_level as u32),
+            _ => (self.false_target, new_worry_level as u32)
+        }
+    }
+
+    fn catch(&mut self, item: u32) {
+        self.items.push(item);
+    }
+}
+
+fn parse(input: &str) -> Vec<Monkey> {
+    input
+        .trim()
+        .split("\n\n")
+        .map(|block| {
+            let lines = block.split("\n").collect::<Vec<&str>>();
+            let op: Vec<&str> = lines[2].split("= ").nth(1).unwrap().split_whitespace().collect();
+            Monkey {
+                items: lines[1].split_terminator(&[':', ',']).filter_map(|num| num.trim().parse::<u32>().ok()).collect(),
+                div: lines[3].split_whitespace().last().unwrap().parse().unwrap(),
+                true_target: lines[4].split_whitespace().last().unwrap().parse().unwrap(),
+                false_target: lines[5].split_whitespace().last().unwrap().parse().unwrap(),
+                operand: op[1].chars().next().unwrap(),
+                operators: (op[0].to_string(), op[2].to_string()) }
+        })
+    .collect()
+}
+
+// part 2 state between rounds, so long games can be checkpointed
+#[derive(Debug, Serialize, Deserialize)]
+struct KeepAway {
+    monkeys: Vec<Monkey>,
+    inspected_items: Vec<u32>,
+    round: u32,
+}
+
+pub fn part01(input: &str) -> Result<u32> {
+    let mut monkeys = parse(input);
+
+    let mut inspected_items: Vec<u32> = vec![0; monkeys.len()];
+    for round in 0..20 {
+        let _round = tracing::trace_span!("round", round).entered();
+        for monkey in 0..monkeys.len() {
+            for item_idx in 0..monkeys[monkey].items.len() {
+                let item = monkeys[monkey].items[item_idx];
+                let (target_monkey, new_worry_level) = monkeys[monkey].decide_target(item)
+                    .with_context(|| format!("monkey {} inspecting an item in round {}", monkey, round + 1))?;
+                monkeys[target_monkey].catch(new_worry_level);
+            }
+            inspected_items[monkey] += monkeys[monkey].items.len() as u32;
+            monkeys[monkey].items.clear();
+        }
+    }
+
+    inspected_items.sort_by(|a, b| b.cmp(a));
+    Ok(checked::mul(inspected_items[0], inspected_items[1])?)
+}
+
+pub fn part02(input: &str) -> Result<u64> {
+    let mut game = match checkpoint::resume()? {
+        Some(game) => game,
+        None => {
+            let monkeys = parse(input);
+            KeepAway { inspected_items: vec![0; monkeys.len()], monkeys, round: 0 }
+        }
+    };
+
+    let worry_mod = game.monkeys.iter().try_fold(1, |acc, monkey| checked::mul(acc, monkey.div))?;
+    while game.round < 10000 {
+        let _round = tracing::trace_span!("round", round = game.round).entered();
+        let KeepAway { monkeys, inspected_items, .. } = &mut game;
+        for monkey in 0..monkeys.len() {
+            for item_idx in 0..monkeys[monkey].items.len() {
+                let item = monkeys[monkey].items[item_idx];
+                let (target_monkey, new_worry_level) = monkeys[monkey].decide_target_2(item, worry_mod);
+                monkeys[target_monkey].catch(new_worry_level);
+            }
+            inspected_items[monkey] += monkeys[monkey].items.len() as u32;
+            monkeys[monkey].items.clear();
+        }
+        game.round += 1;
+        checkpoint::save(game.round as u64, || &game)?;
+    }
+    let mut inspected_items = game.inspected_items;
+    tracing::debug!(?inspected_items, "monkey business");
+
+    inspected_items.sort_by(|a, b| b.cmp(a));
+    Ok(inspected_items[0] as u64 * inspected_items[1] as u64)
+}
@@ -0,0 +1,201 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::collections::BinaryHeap;
+use std::cmp::Ordering;
+
+use crate::differential::Rng;
+use crate::grid::{Grid, Position};
+use crate::search;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Item {
+    distance: usize,
+    position: (usize, usize),
+}
+
+// The priority queue depends on `Ord`.
+// Explicitly implement the trait so the queue becomes a min-heap
+// instead of a max-heap.
+impl Ord for Item {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.cmp(&self.distance)
+            .then_with(|| self.position.cmp(&other.position))
+    }
+}
+
+impl PartialOrd for Item {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn position_to_index((a, b): (usize, usize), dim: usize) -> usize {
+    a * dim + b
+}
+
+fn generate_neighbors((a, b): (usize, usize), dim: (usize, usize)) -> Vec<(usize, usize)> {
+    let mut neighbors = Vec::new();
+    if a + 1 < dim.0 { neighbors.push(( a + 1, b)); }
+    if a as i32 > 0 { neighbors.push((a - 1, b)); }
+    if b + 1 < dim.1 { neighbors.push((a, b + 1)); }
+    if b as i32 > 0 { neighbors.push((a, b - 1)); }
+    neighbors
+}
+
+pub fn part01(input: &str) -> Result<u32> {
+    let mut start: (usize, usize) = (0, 0);
+    let mut end: (usize, usize) = (0, 0);
+    let mut i: i32 = -1;
+    let mut j: i32 = -1;
+    let input: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| {
+            i += 1;
+            j = -1;
+            line.bytes().map(|byte| {
+                j += 1;
+                match byte {
+                    83 => {
+                        start = (i as usize, j as usize);
+                        'a' as u32
+                    },
+                    69 => {
+                        end = (i as usize, j as usize);
+                        'z' as u32
+                    }
+                    _ => byte as u32
+                }
+            }).collect_vec()
+        })
+        .collect();
+    let dim = (input.len(), input[0].len());
+    let mut visited = vec![false; dim.0 * dim.1];
+    let mut dist = vec![usize::MAX; dim.0 * dim.1];
+    let mut pq = BinaryHeap::new();
+    dist[position_to_index(start, dim.1)] = 0;
+    visited[position_to_index(start, dim.1)] = true;
+    pq.push(Item {distance: 0, position: start});
+
+    while let Some(Item {distance, position}) = pq.pop() {
+        tracing::trace!(distance, ?position, "expand");
+        if position == end { return Ok(distance as u32); }
+        if distance > dist[position_to_index(position, dim.1)] { continue; }
+        let val_of_current = input[position.0][position.1];
+        visited[position_to_index(position, dim.1)] = true;
+
+        for neighbor in generate_neighbors(position, dim) {
+            let val_of_neighbor = input[neighbor.0][neighbor.1];
+            if val_of_neighbor > val_of_current + 1 { continue; }
+            if !visited[position_to_index(neighbor, dim.1)] && distance + 1 < dist[position_to_index(neighbor, dim.1)] {
+                pq.push(Item { distance: distance + 1, position: neighbor });
+                dist[position_to_index(neighbor, dim.1)] = distance + 1;
+            }
+        }
+    }
+    Ok(0)
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    let mut starts: Vec<(usize, usize)> = Vec::new();
+    let mut end: (usize, usize) = (0, 0);
+    let mut i: i32 = -1;
+    let mut j: i32 = -1;
+    let input: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| {
+            i += 1;
+            j = -1;
+            line.bytes().map(|byte| {
+                j += 1;
+                match byte {
+                    83 | 97 => {
+                        starts.push((i as usize, j as usize));
+                        'a' as u32
+                    },
+                    69 => {
+                        end = (i as usize, j as usize);
+                        'z' as u32
+                    }
+                    _ => byte as u32
+                }
+            }).collect_vec()
+        })
+        .collect();
+    let dim = (input.len(), input[0].len());
+    let mut visited = vec![false; dim.0 * dim.1];
+    let mut dist = vec![usize::MAX; dim.0 * dim.1];
+    let mut pq = BinaryHeap::new();
+    for start in starts {
+        dist[position_to_index(start, dim.1)] = 0;
+        visited[position_to_index(start, dim.1)] = true;
+        pq.push(Item {distance: 0, position: start});
+    }
+
+    while let Some(Item {distance, position}) = pq.pop() {
+        tracing::trace!(distance, ?position, "expand");
+        if position == end { return Ok(distance as u32); }
+        if distance > dist[position_to_index(position, dim.1)] { continue; }
+        let val_of_current = input[position.0][position.1];
+        visited[position_to_index(position, dim.1)] = true;
+
+        for neighbor in generate_neighbors(position, dim) {
+            let val_of_neighbor = input[neighbor.0][neighbor.1];
+            if val_of_neighbor > val_of_current + 1 { continue; }
+            if !visited[position_to_index(neighbor, dim.1)] && distance + 1 < dist[position_to_index(neighbor, dim.1)] {
+                pq.push(Item { distance: distance + 1, position: neighbor });
+                dist[position_to_index(neighbor, dim.1)] = distance + 1;
+            }
+        }
+    }
+    Ok(0)
+}
+
+fn elevation(c: char) -> u8 {
+    match c {
+        'S' => b'a',
+        'E' => b'z',
+        c => c as u8,
+    }
+}
+
+// breadth first from every square is_start accepts, no path counts as 0 like in the solvers above
+fn climb(input: &str, is_start: impl Fn(char) -> bool) -> Result<u32> {
+    let map = Grid::parse(input, |c| c)?;
+    let end = map.find(|&c| c == 'E').ok_or_else(|| anyhow!("no end position"))?;
+    let heights = map.map(|&c| elevation(c));
+    let starts = map.iter().filter(|(_, &c)| is_start(c)).map(|(pos, _)| pos);
+    let neighbours = |&pos: &Position| heights.neighbours4(pos).filter(|&next| heights[next] <= heights[pos] + 1).collect_vec();
+    let found = search::bfs(starts, neighbours, |&pos| {
+        tracing::trace!(?pos, "expand");
+        pos == end
+    });
+    Ok(found.map_or(0, |(_, distance)| distance as u32))
+}
+
+pub fn part01_bfs(input: &str) -> Result<u32> {
+    climb(input, |c| c == 'S')
+}
+
+pub fn part02_bfs(input: &str) -> Result<u32> {
+    climb(input, |c| c == 'S' || c == 'a')
+}
+
+// a slope from a in the first column to z in the last with some dips and cliffs, S at the bottom and E on top
+pub fn generate(rng: &mut Rng) -> String {
+    let (width, height) = (rng.range(26, 40), rng.range(2, 6));
+    let mut rows = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|col| {
+                    let dip = if rng.chance(1, 4) { 1 } else { 0 };
+                    let cliff = if rng.chance(1, 12) { 3 } else { 0 };
+                    let level = (col * 25 / (width - 1) - dip + cliff).clamp(0, 25);
+                    (b'a' + level as u8) as char
+                })
+                .collect_vec()
+        })
+        .collect_vec();
+    rows[rng.range(0, height - 1) as usize][0] = 'S';
+    rows[rng.range(0, height - 1) as usize][width as usize - 1] = 'E';
+    rows.iter().map(|row| format!("{}\n", row.iter().collect::<String>())).collect()
+}
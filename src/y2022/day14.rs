@@ -0,0 +1,120 @@
+use anyhow::Result;
+use itertools::Itertools;
+use std::collections::HashSet;
+
+use crate::simulation::{Driver, Simulation, Stop};
+
+fn get_range_iter_inclusive(a: usize, b: usize) -> impl Iterator<Item = usize> {
+    if b > a {
+        let vec: Vec<usize> = (a..=b).collect();
+        vec.into_iter()
+    } else {
+        let vec: Vec<usize> = (b..=a).rev().collect();
+        vec.into_iter()
+    }
+}
+
+// blocked squares of the rock paths and the lowest rock
+fn parse(input: &str) -> (HashSet<(usize, usize)>, usize) {
+    let input: Vec<Vec<(usize, usize)>> = input
+        .trim()
+        .lines()
+        .map(|line| line.split(" -> ")
+             .map(|pos| pos.split_once(',').unwrap())
+             .map(|(a,b)| (a.parse().unwrap(), b.parse().unwrap()))
+             .collect())
+        .collect();
+    let mut blocked_positions: HashSet<(usize, usize)> = HashSet::new();
+    let mut down_bound: usize = 0;
+    for rock_piece in input.iter() {
+        for b in 0..rock_piece.len()-1 {
+            for i in get_range_iter_inclusive(rock_piece[b].0, rock_piece[b+1].0) {
+                blocked_positions.insert((i, rock_piece[b].1));
+            }
+            for j in get_range_iter_inclusive(rock_piece[b].1, rock_piece[b+1].1) {
+                down_bound = down_bound.max(j);
+                blocked_positions.insert((rock_piece[b].0, j));
+            }
+        }
+    }
+    (blocked_positions, down_bound)
+}
+
+const SAND_ORIGIN: (usize, usize) = (500, 0);
+
+// one unit of sand falls per step, until sand flows into the abyss or blocks the origin
+struct Cave {
+    rocks: HashSet<(usize, usize)>,
+    blocked_positions: HashSet<(usize, usize)>,
+    down_bound: usize,
+    // part 2 has a floor right below down_bound, part 1 an abyss
+    floor: bool,
+    num_sand_units: u32,
+}
+
+impl Cave {
+    fn new(input: &str, floor: bool) -> Self {
+        let (rocks, down_bound) = parse(input);
+        let down_bound = if floor { down_bound + 1 } else { down_bound };
+        Cave { blocked_positions: rocks.clone(), rocks, down_bound, floor, num_sand_units: 0 }
+    }
+}
+
+impl Simulation for Cave {
+    type Key = ();
+
+    fn step(&mut self) -> bool {
+        if self.blocked_positions.contains(&SAND_ORIGIN) {
+            return false;
+        }
+        let mut sand_pos = SAND_ORIGIN;
+        while sand_pos.1 < self.down_bound {
+            sand_pos.1 += 1;
+            if !self.blocked_positions.contains(&sand_pos) { continue; }
+            sand_pos.0 -= 1;
+            if !self.blocked_positions.contains(&sand_pos) { continue; }
+            sand_pos.0 += 2;
+            if !self.blocked_positions.contains(&sand_pos) { continue; }
+            sand_pos = (sand_pos.0-1, sand_pos.1-1);
+            break;
+        }
+        if !self.floor && sand_pos.1 >= self.down_bound {
+            return false;
+        }
+        self.blocked_positions.insert(sand_pos);
+        self.num_sand_units += 1;
+        true
+    }
+
+    fn render(&self) -> String {
+        let (min_x, max_x) = self.blocked_positions.iter().map(|pos| pos.0).minmax().into_option().unwrap_or((500, 500));
+        (0..=self.down_bound)
+            .map(|y| (min_x..=max_x).map(|x| match (x, y) {
+                pos if self.rocks.contains(&pos) => '#',
+                pos if self.blocked_positions.contains(&pos) => 'o',
+                pos if pos == SAND_ORIGIN => '+',
+                _ => '.',
+            }).collect::<String>())
+            .join("\n")
+    }
+}
+
+fn sand_units(input: &str, floor: bool) -> Result<u32> {
+    let mut driver = Driver::new(Cave::new(input, floor));
+    driver.run(Stop::FixedPoint)?;
+    Ok(driver.simulation.num_sand_units)
+}
+
+pub fn part01(input: &str) -> Result<u32> {
+    sand_units(input, false)
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    sand_units(input, true)
+}
+
+pub fn render_cave(input: &str) -> Result<String> {
+    let mut driver = Driver::new(Cave::new(input, false));
+    driver.run(Stop::FixedPoint)?;
+    Ok(driver.simulation.render())
+}
@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+
+use crate::checked::{self, Cast, Overflow};
+use crate::differential::Rng;
+use crate::interval;
+use crate::parallel;
+
+#[derive(Debug)]
+struct SensorBeaconPair {
+    sensor: (i32, i32),
+    beacon: (i32, i32),
+    distance: u32
+}
+
+fn parse(input: &str) -> Result<Vec<SensorBeaconPair>, Overflow> {
+    let input: Vec<Vec<String>> = input
+        .trim()
+        .lines()
+        .map(|line| line.split_terminator(&[':', ',', '=', ' ']).collect::<Vec<&str>>().into_iter().map(|e| e.to_string()).collect())
+        .collect();
+    input
+        .into_iter()
+        .map(|pair| Ok(SensorBeaconPair {
+            sensor: (pair[3].parse().unwrap(), pair[6].parse().unwrap()),
+            beacon: (pair[13].parse().unwrap(), pair[16].parse().unwrap()),
+            distance: checked::add(pair[3].parse::<i32>().unwrap().abs_diff(pair[13].parse::<i32>().unwrap()),
+                pair[6].parse::<i32>().unwrap().abs_diff(pair[16].parse::<i32>().unwrap()))?
+        })).collect()
+}
+
+pub fn part01(input: &str) -> Result<usize> {
+    let y = 2000000;
+    let mut y_covered_by_sensors: HashSet<i32> = HashSet::new();
+    let sensor_beacon_pairs = parse(input)?;
+
+    let mut beacons_on_y: HashSet<i32> = HashSet::new();
+    for pair in &sensor_beacon_pairs {
+        let dist_to_y = pair.sensor.1.abs_diff(y);
+        if dist_to_y <= pair.distance {
+            let remaining_dist: i32 = (pair.distance - dist_to_y).cast()?;
+            // the ends of the covered stretch, everything between fits if they do
+            checked::sub(pair.sensor.0, remaining_dist)?;
+            checked::add(pair.sensor.0, remaining_dist)?;
+            for i in -remaining_dist..=remaining_dist {
+                y_covered_by_sensors.insert(pair.sensor.0 + i);
+            }
+        }
+        if pair.beacon.1 == y { beacons_on_y.insert(pair.beacon.0); }
+    }
+
+    Ok(y_covered_by_sensors.len() - beacons_on_y.len())
+}
+
+pub fn part01_intervals(input: &str) -> Result<u64> {
+    let y = 2000000;
+    let sensor_beacon_pairs = parse(input)?;
+    let covered = interval::merge(sensor_beacon_pairs.iter().filter_map(|pair| {
+        let remaining_dist = pair.distance.checked_sub(pair.sensor.1.abs_diff(y))? as i64;
+        Some((pair.sensor.0 as i64 - remaining_dist, pair.sensor.0 as i64 + remaining_dist))
+    }));
+    let beacons_on_y: HashSet<i32> = sensor_beacon_pairs.iter().filter(|pair| pair.beacon.1 == y).map(|pair| pair.beacon.0).collect();
+    Ok(interval::covered(&covered) - beacons_on_y.len() as u64)
+}
+
+fn get_outer_sensor_boundary_iterator(sensor: (i32, i32), distance: u32) -> impl std::iter::Iterator<Item = (i32, i32)> {
+    let mut num_positions = 0;
+    let length_of_side = distance + 1;
+    std::iter::from_fn(move || {
+        let i = num_positions % length_of_side;
+        let side = num_positions / length_of_side;
+        num_positions += 1;
+        match side {
+            0 => Some((sensor.0 + ((distance as i32) + 1) - (i as i32), sensor.1 + (i as i32))),
+            1 => Some((sensor.0 - (i as i32), sensor.1 + ((distance as i32) + 1) - (i as i32))),
+            2 => Some((sensor.0 - ((distance as i32) + 1) + (i as i32), sensor.1 - (i as i32))),
+            3 => Some((sensor.0 + (i as i32), sensor.1 - ((distance as i32) + 1) + (i as i32))),
+            _ => None
+        }
+    })
+}
+
+// the corners of the boundary, the iterator stays between them so it cannot overflow if they fit
+fn check_outer_sensor_boundary(sensor: (i32, i32), distance: u32) -> Result<(), Overflow> {
+    let reach: i32 = checked::add(distance, 1)?.cast()?;
+    for coordinate in [sensor.0, sensor.1] {
+        checked::sub(coordinate, reach)?;
+        checked::add(coordinate, reach)?;
+    }
+    Ok(())
+}
+
+// widened so far apart positions cannot overflow
+fn manhatten_distance(a: (i32, i32), b: (i32, i32)) -> u64 {
+    a.0.abs_diff(b.0) as u64 + a.1.abs_diff(b.1) as u64
+}
+
+pub fn part02(input: &str) -> Result<u64> {
+    let dim = 4000000;
+    let sensor_beacon_pairs = parse(input)?;
+
+    for pair in &sensor_beacon_pairs {
+        check_outer_sensor_boundary(pair.sensor, pair.distance).with_context(|| format!("sensor at {:?}", pair.sensor))?;
+    }
+
+    // the first sensor in input order whose boundary has an uncovered spot, like a plain loop would find
+    let uncovered = parallel::find_map_first(&sensor_beacon_pairs, |pair| {
+        get_outer_sensor_boundary_iterator(pair.sensor, pair.distance)
+            .filter(|pos| (0..dim).contains(&pos.0) && (0..dim).contains(&pos.1))
+            .find(|&pos| !sensor_beacon_pairs.iter().any(|other_pair| manhatten_distance(pos, other_pair.sensor) <= other_pair.distance as u64))
+    });
+
+    Ok(uncovered.map_or(0, |pos| (pos.0 as u64) * dim as u64 + pos.1 as u64))
+}
+
+// sensors close to the row part 1 looks at
+pub fn generate(rng: &mut Rng) -> String {
+    (0..rng.range(1, 12))
+        .map(|_| {
+            let sensor = (rng.range(-100_000, 100_000), rng.range(2_000_000 - 30_000, 2_000_000 + 30_000));
+            let mut beacon = (sensor.0 + rng.range(-20_000, 20_000), sensor.1 + rng.range(-20_000, 20_000));
+            if rng.chance(1, 4) {
+                beacon.1 = 2_000_000;
+            }
+            format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", sensor.0, sensor.1, beacon.0, beacon.1)
+        })
+        .collect()
+}
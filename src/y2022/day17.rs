@@ -0,0 +1,244 @@
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+
+use crate::differential::Rng;
+use crate::progress;
+use crate::simulation::{Driver, Outcome, Simulation, Stop};
+
+#[derive(Debug)]
+struct Rock {
+    shape: Vec<(i32, i32)>,
+    bottom: i32,
+    left: i32,
+    right: i32,
+    height: usize
+}
+
+impl Rock {
+    fn translate(&mut self, (x, y): (i32, i32)) {
+        for pos in &mut self.shape {
+            *pos = (pos.0 + x, pos.1 + y);
+        }
+        self.bottom += y;
+        self.left += x;
+        self.right += x;
+    }
+}
+
+fn rock_generator(n: u32) -> impl std::iter::Iterator<Item = Rock> {
+    let mut num_rocks = 0;
+    std::iter::from_fn(move || {
+        if num_rocks >= n { return None }
+        let shape = num_rocks % 5;
+        num_rocks += 1;
+        match shape {
+            0 => Some(Rock {shape: vec![(0,0), (1,0), (2,0), (3,0)], bottom: 0, left: 0, right: 3, height: 1}),
+            1 => Some(Rock {shape: vec![(0,1), (1,1), (2,1), (1,0), (1, 2)], bottom: 0, left: 0, right: 2, height: 3}),
+            2 => Some(Rock {shape: vec![(0,0), (1,0), (2,0), (2,1), (2, 2)], bottom: 0, left: 0, right: 2, height: 3}),
+            3 => Some(Rock {shape: vec![(0,0), (0,1), (0,2), (0,3)], bottom: 0, left: 0, right: 0, height: 4}),
+            4 => Some(Rock {shape: vec![(0,0), (0,1), (1,0), (1,1)], bottom: 0, left: 0, right: 1, height: 2}),
+            _ => unreachable!()
+        }
+    })
+}
+
+fn get_direction(wind: &mut impl Iterator<Item = i32>, rock: &Rock) -> (i32, i32) {
+    match wind.next() {
+        Some(-1) if rock.left > 0 => (-1, -1),
+        Some(1) if rock.right < 6 => (1, -1),
+        Some(_) => (0, -1),
+        None => unreachable!()
+    }
+
+}
+
+pub fn part01(input: &str) -> Result<u32> {
+    let input = input
+        .trim()
+        .chars()
+        .map(|char| match char {
+            '<' => -1,
+            '>' => 1,
+            _ => unreachable!()
+        })
+        .collect::<Vec<i32>>();
+        let mut wind = input
+        .into_iter()
+        .cycle();
+    let total_num_rocks = 2022;
+    let cave_width = 7;
+    let mut cave = vec![vec![true; cave_width]; 1];
+
+    for (i, mut rock) in rock_generator(total_num_rocks).enumerate() {
+        if i % 1024 == 0 {
+            progress::check()?;
+        }
+        rock.translate((2, (cave.len() + 3) as i32));
+        while rock.bottom > cave.len() as i32 {
+            rock.translate(get_direction(&mut wind, &rock));
+        }
+
+        let mut direction = get_direction(&mut wind, &rock);
+        loop {
+            if rock.shape.iter().any(|(x, y)| if *y >= cave.len() as i32 { false } else { cave[*y as usize][(x + direction.0) as usize] }) { direction.0 = 0; }
+            if rock.shape.iter().any(|(x, y)| if *y + direction.1 >= cave.len() as i32 { false } else { cave[(y + direction.1) as usize][(x + direction.0) as usize] }) { direction.1 = 0; }
+            rock.translate(direction);
+            if direction.1 == 0 { break; }
+            direction = get_direction(&mut wind, &rock);
+        }
+
+        while cave.len() < rock.bottom as usize + rock.height {
+            cave.push(vec![false; cave_width]);
+        }
+
+        for (x, y) in &rock.shape {
+            cave[*y as usize][*x as usize] = true;
+        }
+        tracing::trace!(bottom = rock.bottom, height = cave.len(), "rock settled");
+    }
+    Ok((cave.len() - 1) as u32)
+}
+
+// samples 100000 rocks and extrapolates from the period the heights repeat with
+pub fn part02_sampling(input: &str) -> Result<u64> {
+    let input = input
+        .trim()
+        .chars()
+        .map(|char| match char {
+            '<' => -1,
+            '>' => 1,
+            _ => unreachable!()
+        })
+        .collect::<Vec<i32>>();
+        let mut wind = input
+        .into_iter()
+        .cycle();
+    let mut height_after_rock = Vec::with_capacity(2022);
+    let total_num_rocks = 100_000;
+    let cave_width = 7;
+    let mut cave: Vec<Vec<bool>> = Vec::new();
+
+    for (i, mut rock) in rock_generator(total_num_rocks).enumerate() {
+        if i % 1024 == 0 {
+            progress::check()?;
+        }
+        rock.translate((2, (cave.len() + 3) as i32));
+
+        loop {
+            let mut direction = get_direction(&mut wind, &rock);
+            if rock.shape.iter().any(|(x, y)| if *y >= cave.len() as i32 { false } else { cave[*y as usize][(x + direction.0) as usize] }) { direction.0 = 0; }
+            if rock.bottom == 0 || rock.shape.iter().any(|(x, y)| if *y + direction.1 >= cave.len() as i32 { false } else { cave[(y + direction.1) as usize][(x + direction.0) as usize] }) { direction.1 = 0; }
+            rock.translate(direction);
+            if direction.1 == 0 { break; }
+        }
+
+        while cave.len() < rock.bottom as usize + rock.height {
+            cave.push(vec![false; cave_width]);
+        }
+
+        for (x, y) in &rock.shape {
+            cave[*y as usize][*x as usize] = true;
+        }
+        tracing::trace!(bottom = rock.bottom, height = cave.len(), "rock settled");
+
+        height_after_rock.push(cave.len());
+    }
+
+    // by the second half the tower grows by the same height every period, checked rock by rock
+    let settled = height_after_rock.len() / 2;
+    let (periode, periode_height) = (1..settled / 2)
+        .find_map(|num_rocks| {
+            let h = height_after_rock[settled + num_rocks] - height_after_rock[settled];
+            (settled..height_after_rock.len() - num_rocks).all(|i| height_after_rock[i + num_rocks] - height_after_rock[i] == h).then_some((num_rocks, h))
+        })
+        .ok_or_else(|| anyhow!("the heights do not repeat within {} rocks", settled / 2))?;
+    // height_after_rock[i] is the height after i + 1 rocks, extrapolated from the settled sample in the same phase
+    let last = 1_000_000_000_000_u64 - 1;
+    let base = settled + ((last - settled as u64) % periode as u64) as usize;
+    let num_cycles = (last - base as u64) / periode as u64;
+    Ok(height_after_rock[base] as u64 + num_cycles * periode_height as u64)
+}
+
+// rows of the rocks from the bottom up, bit i is column i
+const SHAPES: [&[u8]; 5] = [&[0b1111], &[0b010, 0b111, 0b010], &[0b111, 0b100, 0b100], &[1, 1, 1, 1], &[0b11, 0b11]];
+const WIDTHS: [usize; 5] = [4, 3, 3, 1, 2];
+// rows compared to recognize a repeating state
+const PROFILE_ROWS: usize = 32;
+
+struct Chamber {
+    rows: Vec<u8>,
+    jets: Vec<i32>,
+    jet: usize,
+    rocks: usize,
+    // tower height after every rock, to measure how much a cycle adds
+    heights: Vec<usize>,
+}
+
+impl Chamber {
+    fn collides(&self, shape: &[u8], x: usize, y: usize) -> bool {
+        shape.iter().enumerate().any(|(i, row)| self.rows.get(y + i).is_some_and(|r| r & (row << x) != 0))
+    }
+}
+
+// a step drops one rock, the state repeats once rock, jet and the top rows do
+impl Simulation for Chamber {
+    type Key = (usize, usize, Vec<u8>);
+
+    fn step(&mut self) -> bool {
+        let (shape, width) = (SHAPES[self.rocks % 5], WIDTHS[self.rocks % 5]);
+        let (mut x, mut y) = (2, self.rows.len() + 3);
+        loop {
+            let pushed = x as i32 + self.jets[self.jet];
+            self.jet = (self.jet + 1) % self.jets.len();
+            if (0..=(7 - width) as i32).contains(&pushed) && !self.collides(shape, pushed as usize, y) {
+                x = pushed as usize;
+            }
+            if y == 0 || self.collides(shape, x, y - 1) { break; }
+            y -= 1;
+        }
+        for (i, row) in shape.iter().enumerate() {
+            if y + i == self.rows.len() { self.rows.push(0); }
+            self.rows[y + i] |= row << x;
+        }
+        self.rocks += 1;
+        self.heights.push(self.rows.len());
+        true
+    }
+
+    fn key(&self) -> Option<Self::Key> {
+        (self.rows.len() >= PROFILE_ROWS).then(|| (self.rocks % 5, self.jet, self.rows.iter().rev().take(PROFILE_ROWS).copied().collect()))
+    }
+
+    fn render(&self) -> String {
+        self.rows.iter().rev().map(|row| (0..7).map(|x| if row & 1 << x != 0 { '#' } else { '.' }).collect::<String>()).join("\n")
+    }
+}
+
+// drops rocks until the state repeats and skips the remaining whole cycles
+fn tower_height(input: &str, total_num_rocks: u64) -> Result<u64> {
+    let jets = input.trim().chars().map(|c| if c == '<' { -1 } else { 1 }).collect_vec();
+    let mut driver = Driver::new(Chamber { rows: Vec::new(), jets, jet: 0, rocks: 0, heights: vec![0] }).detect_cycles();
+    let mut skipped_height = 0;
+    if let Outcome::Cycle(cycle) = driver.run(Stop::Steps(total_num_rocks))? {
+        let heights = &driver.simulation.heights;
+        let period_height = (heights[(cycle.start + cycle.length) as usize] - heights[cycle.start as usize]) as u64;
+        let cycles = (total_num_rocks - driver.steps()) / cycle.length;
+        tracing::debug!(period = cycle.length, period_height, cycles, "skipping cycles");
+        skipped_height = cycles * period_height;
+        driver.skip(cycles * cycle.length);
+        driver.run(Stop::Steps(total_num_rocks))?;
+    }
+    Ok(driver.simulation.rows.len() as u64 + skipped_height)
+}
+
+pub fn part01_cycles(input: &str) -> Result<u64> {
+    tower_height(input, 2022)
+}
+
+pub fn part02(input: &str) -> Result<u64> {
+    tower_height(input, 1_000_000_000_000)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    (0..rng.range(1, 200)).map(|_| if rng.chance(1, 2) { '<' } else { '>' }).chain(['\n']).collect()
+}
@@ -0,0 +1,415 @@
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicU64, Ordering::Relaxed};
+
+use anyhow::Result;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::branch_bound::{self, Problem, Stats, Strategy};
+use crate::checkpoint;
+use crate::dp::Memo;
+use crate::explain;
+use crate::parallel;
+use crate::progress::{self, Update};
+
+#[derive(Debug)]
+struct Blueprint {
+    ore: u32,
+    clay: u32,
+    obsidian: (u32, u32),
+    geode: (u32, u32)
+}
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq, Serialize, Deserialize)]
+struct State {
+    ore: u32,
+    clay: u32,
+    obsidian: u32,
+    geode: u32,
+    ore_robots: u32,
+    clay_robots: u32,
+    obsidian_robots: u32,
+    geode_robots: u32
+}
+
+impl State {
+    fn update(&mut self) {
+        self.ore += self.ore_robots;
+        self.clay += self.clay_robots;
+        self.obsidian += self.obsidian_robots;
+        self.geode += self.geode_robots;
+    }
+
+    fn timestep(&self, blueprint: &Blueprint, geode_threshold: u32, remaining_time: u32, next_states: &mut HashSet<State>) {
+       let mut base_state = *self;
+       base_state.update();
+       // geode first for pruning
+       if self.ore >= blueprint.geode.0 && self.obsidian >= blueprint.geode.1 {
+           let mut state = base_state;
+           state.geode_robots += 1;
+           state.ore -= blueprint.geode.0;
+           state.obsidian -= blueprint.geode.1;
+           if state.can_prune(geode_threshold, remaining_time - 1) { return; }
+           next_states.insert(state);
+       }
+       if base_state.can_prune(geode_threshold, remaining_time - 1) { return; }
+       next_states.insert(base_state);
+       if self.ore >= blueprint.ore {
+           let mut state = base_state;
+           state.ore_robots += 1;
+           state.ore -= blueprint.ore;
+           next_states.insert(state);
+       }
+       if self.ore >= blueprint.clay {
+           let mut state = base_state;
+           state.clay_robots += 1;
+           state.ore -= blueprint.clay;
+           next_states.insert(state);
+       }
+       if self.ore >= blueprint.obsidian.0 && self.clay >= blueprint.obsidian.1 {
+           let mut state = base_state;
+           state.obsidian_robots += 1;
+           state.ore -= blueprint.obsidian.0;
+           state.clay -= blueprint.obsidian.1;
+           next_states.insert(state);
+       }
+    }
+
+    fn can_prune(&self, geode_threshold: u32, remaining_time: u32) -> bool {
+        self.geode_lower_bound(remaining_time) + (0..remaining_time).sum::<u32>() < geode_threshold
+    }
+
+    fn geode_lower_bound(&self, remaining_time: u32) -> u32 {
+        self.geode + self.geode_robots * remaining_time
+    }
+
+}
+
+fn parse(input: &str) -> Vec<Blueprint> {
+    input
+        .trim()
+        .lines()
+        .map(|line| {
+            let costs = line.split_whitespace().filter_map(|word| word.parse::<u32>().ok()).collect_vec();
+            Blueprint { ore: costs[0], clay: costs[1], obsidian: (costs[2], costs[3]), geode: (costs[4], costs[5]) }
+        })
+    .collect()
+}
+
+// search state at the start of a minute, checkpointed so the 32 minute runs can be resumed
+#[derive(Debug, Serialize, Deserialize)]
+struct Progress {
+    geodes: Vec<u32>,
+    remaining_time: u32,
+    states: Vec<State>,
+    geode_lower_bound: u32,
+}
+
+// one minute of the search, the states that can still beat the lower bound a minute later
+fn minute(blueprint: &Blueprint, states: &HashSet<State>, geode_lower_bound: &mut u32, t: u32) -> Result<HashSet<State>> {
+    let mut next_states = HashSet::new();
+    for (i, state) in states.iter().enumerate() {
+        if i % 4096 == 0 {
+            progress::check()?;
+        }
+        if state.can_prune(*geode_lower_bound, t) { continue; }
+        *geode_lower_bound = (*geode_lower_bound).max(state.geode_lower_bound(t));
+        state.timestep(blueprint, *geode_lower_bound, t, &mut next_states);
+    }
+    tracing::trace!(remaining_time = t, expanded = next_states.len(), geode_lower_bound, "minute");
+    Ok(next_states)
+}
+
+const START_STATE: State = State { ore: 0, clay: 0, obsidian: 0, geode: 0, ore_robots: 1, clay_robots: 0, obsidian_robots: 0, geode_robots: 0 };
+
+// the geodes and the number of states searched
+fn blueprint_geodes(id: usize, blueprint: &Blueprint, time: u32) -> Result<(u32, u64)> {
+    let _blueprint = tracing::debug_span!("blueprint", id).entered();
+    let mut states = HashSet::from([START_STATE]);
+    let (mut geode_lower_bound, mut searched) = (0, 0);
+    for t in (1..=time).rev() {
+        states = minute(blueprint, &states, &mut geode_lower_bound, t)?;
+        searched += states.len() as u64;
+    }
+    tracing::debug!(geodes = geode_lower_bound, "blueprint evaluated");
+    Ok((geode_lower_bound, searched))
+}
+
+// counts a finished blueprint, the workers share the caller's progress bar through their context
+fn blueprint_done(done: &AtomicU64, blueprints: usize, states: u64) -> Result<()> {
+    let done = done.fetch_add(1, Relaxed) + 1;
+    progress::report(Update { round: done, rounds: blueprints as u64, states, best: None })
+}
+
+// most geodes each blueprint can open in the given time
+fn max_geodes(blueprints: &[Blueprint], time: u32) -> Result<Vec<u32>> {
+    // a checkpoint records the blueprints done so far, so saving or resuming one keeps them in order
+    if cfg!(feature = "parallel") && !checkpoint::enabled() {
+        let (ids, done) = ((1..=blueprints.len()).collect_vec(), AtomicU64::new(0));
+        return parallel::map(&ids, |&id| {
+            let (geodes, searched) = blueprint_geodes(id, &blueprints[id - 1], time)?;
+            blueprint_done(&done, blueprints.len(), searched)?;
+            Ok(geodes)
+        })
+        .into_iter()
+        .collect();
+    }
+    let mut progress = checkpoint::resume()?.unwrap_or(Progress { geodes: Vec::new(), remaining_time: time, states: vec![START_STATE], geode_lower_bound: 0 });
+    while let Some(blueprint) = blueprints.get(progress.geodes.len()) {
+        let _blueprint = tracing::debug_span!("blueprint", id = progress.geodes.len() + 1).entered();
+        let mut states: HashSet<State> = progress.states.drain(..).collect();
+        let mut geode_lower_bound = progress.geode_lower_bound;
+        for t in (1..=progress.remaining_time).rev() {
+            states = minute(blueprint, &states, &mut geode_lower_bound, t)?;
+            let step = (progress.geodes.len() as u32 * time + time - t + 1) as u64;
+            progress::report(Update { round: step, rounds: (blueprints.len() as u32 * time) as u64, states: states.len() as u64, best: Some(geode_lower_bound as u64) })?;
+            checkpoint::save(step, || Progress {
+                geodes: progress.geodes.clone(),
+                remaining_time: t - 1,
+                states: states.iter().copied().collect(),
+                geode_lower_bound,
+            })?;
+        }
+        tracing::debug!(geodes = geode_lower_bound, "blueprint evaluated");
+        progress = Progress { geodes: [progress.geodes, vec![geode_lower_bound]].concat(), remaining_time: time, states: vec![START_STATE], geode_lower_bound: 0 };
+    }
+    Ok(progress.geodes)
+}
+
+pub fn part01_frontier(input: &str) -> Result<u32> {
+    let geodes = max_geodes(&parse(input), 24)?;
+    Ok(geodes.iter().enumerate().map(|(i, geodes)| (i + 1) as u32 * geodes).sum())
+}
+
+pub fn part02_frontier(input: &str) -> Result<u32> {
+    let blueprints = parse(input).into_iter().take(3).collect_vec();
+    Ok(max_geodes(&blueprints, 32)?.iter().product())
+}
+
+impl Blueprint {
+    fn max_ore_cost(&self) -> u32 {
+        self.ore.max(self.clay).max(self.obsidian.0).max(self.geode.0)
+    }
+}
+
+// resources beyond what can still be spent make no difference, capping them lets equal futures share a memo entry
+fn canonical(blueprint: &Blueprint, mut state: State, remaining_time: u32) -> State {
+    let cap = |amount: u32, robots: u32, cost: u32| amount.min((cost * remaining_time).saturating_sub(robots * remaining_time.saturating_sub(1)));
+    state.ore = cap(state.ore, state.ore_robots, blueprint.max_ore_cost());
+    state.clay = cap(state.clay, state.clay_robots, blueprint.obsidian.1);
+    state.obsidian = cap(state.obsidian, state.obsidian_robots, blueprint.geode.1);
+    state
+}
+
+fn most_geodes(memo: &mut Memo<(u32, State), u32, Blueprint>, blueprint: &Blueprint, &(remaining_time, state): &(u32, State)) -> u32 {
+    if remaining_time == 0 {
+        return state.geode;
+    }
+    let mut produced = state;
+    produced.update();
+    let mut options = vec![produced];
+    if state.ore >= blueprint.geode.0 && state.obsidian >= blueprint.geode.1 {
+        options.push(State { ore: produced.ore - blueprint.geode.0, obsidian: produced.obsidian - blueprint.geode.1, geode_robots: produced.geode_robots + 1, ..produced });
+    }
+    if state.ore >= blueprint.obsidian.0 && state.clay >= blueprint.obsidian.1 && state.obsidian_robots < blueprint.geode.1 {
+        options.push(State { ore: produced.ore - blueprint.obsidian.0, clay: produced.clay - blueprint.obsidian.1, obsidian_robots: produced.obsidian_robots + 1, ..produced });
+    }
+    if state.ore >= blueprint.clay && state.clay_robots < blueprint.obsidian.1 {
+        options.push(State { ore: produced.ore - blueprint.clay, clay_robots: produced.clay_robots + 1, ..produced });
+    }
+    if state.ore >= blueprint.ore && state.ore_robots < blueprint.max_ore_cost() {
+        options.push(State { ore: produced.ore - blueprint.ore, ore_robots: produced.ore_robots + 1, ..produced });
+    }
+    options.into_iter().map(|next| memo.get(blueprint, &(remaining_time - 1, canonical(blueprint, next, remaining_time - 1)))).max().unwrap_or(0)
+}
+
+fn max_geodes_memo(blueprints: &[Blueprint], time: u32) -> Vec<u32> {
+    parallel::map(blueprints, |blueprint| {
+        let mut memo = Memo::new(most_geodes);
+        let geodes = memo.get(blueprint, &(time, START_STATE));
+        memo.report("geodes");
+        geodes
+    })
+}
+
+pub fn part01_memo(input: &str) -> Result<u32> {
+    let geodes = max_geodes_memo(&parse(input), 24);
+    Ok(geodes.iter().enumerate().map(|(i, geodes)| (i + 1) as u32 * geodes).sum())
+}
+
+pub fn part02_memo(input: &str) -> Result<u32> {
+    let blueprints = parse(input).into_iter().take(3).collect_vec();
+    Ok(max_geodes_memo(&blueprints, 32).iter().product())
+}
+
+// branch and bound over which robot to build next, waiting until it is affordable
+struct Geodes<'a> {
+    blueprint: &'a Blueprint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Robot {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+impl Geodes<'_> {
+    // state after waiting for the resources and building the robot, None if it cannot pay off in time
+    fn build(&self, (remaining_time, state): (u32, State), robot: Robot) -> Option<(u32, State)> {
+        let blueprint = self.blueprint;
+        let ((ore, clay, obsidian), robots, enough) = match robot {
+            Robot::Ore => ((blueprint.ore, 0, 0), state.ore_robots, blueprint.max_ore_cost()),
+            Robot::Clay => ((blueprint.clay, 0, 0), state.clay_robots, blueprint.obsidian.1),
+            Robot::Obsidian => ((blueprint.obsidian.0, blueprint.obsidian.1, 0), state.obsidian_robots, blueprint.geode.1),
+            Robot::Geode => ((blueprint.geode.0, 0, blueprint.geode.1), state.geode_robots, u32::MAX),
+        };
+        // more robots of a kind than any robot costs of its resource cannot be spent
+        if robots >= enough {
+            return None;
+        }
+        let wait = |cost: u32, have: u32, robots: u32| match (cost.saturating_sub(have), robots) {
+            (0, _) => Some(0),
+            (_, 0) => None,
+            (missing, robots) => Some(missing.div_ceil(robots)),
+        };
+        let wait = wait(ore, state.ore, state.ore_robots)?.max(wait(clay, state.clay, state.clay_robots)?).max(wait(obsidian, state.obsidian, state.obsidian_robots)?);
+        if wait + 1 >= remaining_time {
+            return None;
+        }
+        let minutes = wait + 1;
+        let mut next = State {
+            ore: state.ore + state.ore_robots * minutes - ore,
+            clay: state.clay + state.clay_robots * minutes - clay,
+            obsidian: state.obsidian + state.obsidian_robots * minutes - obsidian,
+            geode: state.geode + state.geode_robots * minutes,
+            ..state
+        };
+        match robot {
+            Robot::Ore => next.ore_robots += 1,
+            Robot::Clay => next.clay_robots += 1,
+            Robot::Obsidian => next.obsidian_robots += 1,
+            Robot::Geode => next.geode_robots += 1,
+        }
+        Some((remaining_time - minutes, next))
+    }
+}
+
+impl Problem for Geodes<'_> {
+    type State = (u32, State);
+    type Key = (u32, u32, u32, u32, u32);
+
+    fn successors(&self, &plan: &(u32, State), next: &mut Vec<(u32, State)>) {
+        // geode robots first, they find a good incumbent soonest
+        next.extend([Robot::Geode, Robot::Obsidian, Robot::Clay, Robot::Ore].into_iter().filter_map(|robot| self.build(plan, robot)));
+    }
+
+    // geodes at the end when no more robots are built
+    fn objective(&self, &(remaining_time, state): &(u32, State)) -> u64 {
+        state.geode_lower_bound(remaining_time) as u64
+    }
+
+    // as if a geode robot were built every remaining minute
+    fn upper_bound(&self, plan: &(u32, State)) -> u64 {
+        let remaining_time = plan.0 as u64;
+        self.objective(plan) + remaining_time * remaining_time.saturating_sub(1) / 2
+    }
+
+    fn dominance_key(&self, &(remaining_time, state): &(u32, State)) -> Option<Self::Key> {
+        Some((remaining_time, state.ore_robots, state.clay_robots, state.obsidian_robots, state.geode_robots))
+    }
+
+    fn dominates(&self, (_, a): &(u32, State), (_, b): &(u32, State)) -> bool {
+        a.ore >= b.ore && a.clay >= b.clay && a.obsidian >= b.obsidian && a.geode >= b.geode
+    }
+}
+
+impl Robot {
+    // the robot built between two consecutive states of a plan
+    fn built(from: &State, to: &State) -> Robot {
+        if to.ore_robots > from.ore_robots {
+            Robot::Ore
+        } else if to.clay_robots > from.clay_robots {
+            Robot::Clay
+        } else if to.obsidian_robots > from.obsidian_robots {
+            Robot::Obsidian
+        } else {
+            Robot::Geode
+        }
+    }
+}
+
+// the states a plan passes through, with the time left in each
+type Plan = Vec<(u32, State)>;
+
+// most geodes for one blueprint, with the plan reaching them when traced
+fn blueprint_plan(blueprint: &Blueprint, time: u32, traced: bool) -> Result<(u32, Plan, Stats)> {
+    let (problem, start) = (Geodes { blueprint }, (time, START_STATE));
+    let (value, plan, stats) = match traced {
+        true => {
+            let solution = branch_bound::maximize_traced(&problem, start, Strategy::DepthFirst)?;
+            (solution.value, solution.state, solution.stats)
+        }
+        false => {
+            let solution = branch_bound::maximize(&problem, start, Strategy::DepthFirst)?;
+            (solution.value, Vec::new(), solution.stats)
+        }
+    };
+    stats.report("geodes");
+    Ok((value as u32, plan, stats))
+}
+
+// most geodes for every blueprint, with the plan reaching them when it is explained
+fn max_geodes_bnb(blueprints: &[Blueprint], time: u32) -> Result<Vec<(u32, Plan)>> {
+    let (traced, done) = (explain::enabled(), AtomicU64::new(0));
+    // a checkpoint holds the blueprints finished so far, one after the other so they stay in order
+    if checkpoint::enabled() {
+        let mut solved: Vec<(u32, Plan)> = checkpoint::resume()?.unwrap_or_default();
+        done.store(solved.len() as u64, Relaxed);
+        while let Some(blueprint) = blueprints.get(solved.len()) {
+            let _blueprint = tracing::debug_span!("blueprint", id = solved.len() + 1).entered();
+            let (geodes, plan, stats) = blueprint_plan(blueprint, time, traced)?;
+            solved.push((geodes, plan));
+            blueprint_done(&done, blueprints.len(), stats.expanded)?;
+            checkpoint::save(solved.len() as u64, || &solved)?;
+        }
+        return Ok(solved);
+    }
+    parallel::map(blueprints, |blueprint| {
+        let (geodes, plan, stats) = blueprint_plan(blueprint, time, traced)?;
+        blueprint_done(&done, blueprints.len(), stats.expanded)?;
+        Ok((geodes, plan))
+    })
+    .into_iter()
+    .collect()
+}
+
+fn explain_plan(id: usize, geodes: u32, plan: &[(u32, State)], time: u32) {
+    // resumed from a checkpoint saved without explaining
+    if plan.is_empty() {
+        explain::line(|| format!("blueprint {}: {} geodes, plan not in the checkpoint", id, geodes));
+        return;
+    }
+    let builds = plan.iter().tuple_windows().map(|((_, from), (remaining_time, to))| format!("{} {:?}", time - remaining_time, Robot::built(from, to)).to_lowercase()).join(", ");
+    explain::line(|| format!("blueprint {}: {} geodes, builds in minute {}", id, geodes, builds));
+}
+
+// the geodes of every blueprint, each plan explained when asked
+fn solve(blueprints: &[Blueprint], time: u32) -> Result<Vec<u32>> {
+    let solved = max_geodes_bnb(blueprints, time)?;
+    if explain::enabled() {
+        solved.iter().enumerate().for_each(|(i, (geodes, plan))| explain_plan(i + 1, *geodes, plan, time));
+    }
+    Ok(solved.into_iter().map(|(geodes, _)| geodes).collect())
+}
+
+pub fn part01(input: &str) -> Result<u32> {
+    let geodes = solve(&parse(input), 24)?;
+    Ok(geodes.iter().enumerate().map(|(i, geodes)| (i + 1) as u32 * geodes).sum())
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    let blueprints = parse(input).into_iter().take(3).collect_vec();
+    Ok(solve(&blueprints, 32)?.iter().product())
+}
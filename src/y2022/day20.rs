@@ -0,0 +1,118 @@
+use anyhow::Result;
+use itertools::Itertools;
+
+use crate::differential::Rng;
+
+pub fn part01(input: &str) -> Result<i32> {
+    let original_order = input
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (line.parse::<i32>().unwrap(), i))
+        .collect_vec();
+    let mut numbers = original_order.clone();
+    // let modulus = numbers.len() as i32 - 1;
+    let num_nums = numbers.len() as i32;
+    for (n, i) in &original_order {
+        let mut current_idx = numbers.iter().position(|(m, j)| m == n && i == j).unwrap() as i32;
+        let mut remaining_steps = *n;
+        while remaining_steps != 0 {
+            let new_idx = if *n > 0 {
+                remaining_steps -= 1;
+                (current_idx + 1) % num_nums
+            } else {
+                remaining_steps += 1;
+                ((current_idx - 1) + num_nums) % num_nums
+            };
+            numbers.swap(current_idx as usize, new_idx as usize);
+            current_idx = new_idx;
+        }
+    }
+    let idx_of_zero = numbers.iter().position(|n| n.0 == 0).unwrap();
+    Ok((1..=3).fold(0, |acc, i| acc + numbers[(idx_of_zero + i * 1000) % num_nums as usize].0))
+}
+
+pub fn part02(input: &str) -> Result<i64> {
+    const DEC_KEY: i64 = 811589153;
+    let original_order = input
+        .trim()
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (line.parse::<i64>().unwrap() * DEC_KEY, i))
+        .collect_vec();
+    let mut numbers = original_order.clone();
+    let modulus = numbers.len() as i64 - 1;
+    let num_nums = numbers.len() as i32;
+    for _ in 0..10 {
+        for (n, i) in &original_order {
+            let mut current_idx = numbers.iter().position(|(m, j)| m == n && i == j).unwrap() as i32;
+            let mut remaining_steps = *n % modulus;
+            while remaining_steps != 0 {
+                let new_idx = if *n > 0 {
+                    remaining_steps -= 1;
+                    (current_idx + 1) % num_nums
+                } else {
+                    remaining_steps += 1;
+                    ((current_idx - 1) + num_nums) % num_nums
+                };
+                numbers.swap(current_idx as usize, new_idx as usize);
+                current_idx = new_idx;
+            }
+        }
+    }
+    let idx_of_zero = numbers.iter().position(|n| n.0 == 0).unwrap();
+    Ok((1..=3).fold(0, |acc, i| acc + numbers[(idx_of_zero + i * 1000) % num_nums as usize].0))
+}
+
+// doubly linked ring over the original positions, so a number moves |n| % (len - 1)
+// steps without searching for it first
+fn mix_linked(numbers: &[i64], rounds: usize) -> i64 {
+    let len = numbers.len();
+    let mut next: Vec<usize> = (1..=len).map(|i| i % len).collect();
+    let mut prev: Vec<usize> = (0..len).map(|i| (i + len - 1) % len).collect();
+    for _ in 0..rounds {
+        for (i, n) in numbers.iter().enumerate() {
+            let steps = n.rem_euclid(len as i64 - 1) as usize;
+            if steps == 0 { continue; }
+            next[prev[i]] = next[i];
+            prev[next[i]] = prev[i];
+            let mut target = prev[i];
+            for _ in 0..steps {
+                target = next[target];
+            }
+            let after = next[target];
+            (next[target], prev[i], next[i], prev[after]) = (i, target, after, i);
+        }
+    }
+    let mut current = numbers.iter().position(|&n| n == 0).unwrap();
+    let mut sum = 0;
+    for step in 1..=3000 {
+        current = next[current];
+        if step % 1000 == 0 { sum += numbers[current]; }
+    }
+    sum
+}
+
+fn parse(input: &str) -> Vec<i64> {
+    input.trim().lines().map(|line| line.parse().unwrap()).collect()
+}
+
+pub fn part01_linked(input: &str) -> Result<i64> {
+    Ok(mix_linked(&parse(input), 1))
+}
+
+pub fn part02_linked(input: &str) -> Result<i64> {
+    const DEC_KEY: i64 = 811589153;
+    let numbers = parse(input).into_iter().map(|n| n * DEC_KEY).collect_vec();
+    Ok(mix_linked(&numbers, 10))
+}
+
+// duplicates and numbers larger than the list are allowed, zero appears exactly once
+pub fn generate(rng: &mut Rng) -> String {
+    let len = rng.range(2, 60);
+    let bound = if rng.chance(1, 2) { len } else { 1000 };
+    let mut numbers = (1..len).map(|_| rng.range(-bound, bound - 1)).map(|n| if n >= 0 { n + 1 } else { n }).collect_vec();
+    numbers.push(0);
+    rng.shuffle(&mut numbers);
+    numbers.iter().map(|n| format!("{}\n", n)).collect()
+}
@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use anyhow::{anyhow, bail, Result};
+
+use crate::checked::{self, Overflow};
+use crate::dp::Memo;
+use crate::explain;
+use crate::repl::Session;
+
+#[derive(Debug, Clone)]
+enum Expression {
+    Value(i64),
+    Expression((String, char, String))
+}
+
+type Expressions = HashMap<String, Expression>;
+
+fn yell(memo: &mut Memo<String, Result<i64, Overflow>, Expressions>, map: &Expressions, name: &String) -> Result<i64, Overflow> {
+    match &map[name] {
+        Expression::Value(i) => Ok(*i),
+        Expression::Expression((left, op, right)) => {
+            let (op_1, op_2) = (memo.get(map, left)?, memo.get(map, right)?);
+            match op {
+                '+' => checked::add(op_1, op_2),
+                '-' => checked::sub(op_1, op_2),
+                '*' => checked::mul(op_1, op_2),
+                '/' => checked::div(op_1, op_2),
+                _ => Ok(0)
+            }
+            .map_err(|overflow| overflow.at(format!("monkey {}", name)))
+        }
+    }
+}
+
+fn depends_on_human(memo: &mut Memo<String, bool, Expressions>, map: &Expressions, name: &String) -> bool {
+    match &map[name] {
+        _ if name == "humn" => true,
+        Expression::Value(_) => false,
+        Expression::Expression((left, _, right)) => memo.get(map, left) | memo.get(map, right),
+    }
+}
+
+fn eval(map: &Expressions, name: &str) -> Result<i64> {
+    Ok(Memo::new(yell).get(map, &name.to_string())?)
+}
+
+fn parse(input: &str) -> HashMap<String, Expression> {
+    input
+        .trim()
+        .lines()
+        .map(|line| match sscanf::scanf!(line,
+                                   "{String}: {String} {char} {String}") {
+            Ok(tuple) => (tuple.0, Expression::Expression((tuple.1, tuple.2, tuple.3))),
+            Err(_) => {
+                let expr = sscanf::scanf!(line,
+                                  "{String}: {i64}").unwrap();
+                (expr.0, Expression::Value(expr.1))
+                }
+        })
+        .collect()
+}
+
+// solves root = 0 for the human by undoing the operations on the way down, one line per monkey
+fn explain_equation(expressions: &Expressions) -> Result<()> {
+    let mut listening = Memo::new(depends_on_human);
+    let mut memo = Memo::new(yell);
+    let (mut name, mut target) = ("root".to_string(), 0);
+    while let Expression::Expression((left, op, right)) = &expressions[&name] {
+        let (unknown, known) = if listening.get(expressions, left) { (left, right) } else { (right, left) };
+        let k = memo.get(expressions, known)?;
+        let value = match (op, unknown == left) {
+            ('+', _) => checked::sub(target, k)?,
+            ('*', _) => checked::div(target, k)?,
+            ('-', true) => checked::add(target, k)?,
+            ('-', false) => checked::sub(k, target)?,
+            ('/', true) => checked::mul(target, k)?,
+            ('/', false) => checked::div(k, target)?,
+            _ => bail!("monkey {} yells an unknown operation {}", name, op),
+        };
+        explain::line(|| format!("{} = {} {} {} must be {} with {} = {}, so {} = {}", name, left, op, right, target, known, k, unknown, value));
+        (name, target) = (unknown.clone(), value);
+    }
+    Ok(())
+}
+
+pub fn part01(input: &str) -> Result<i64> {
+    let expressions = parse(input);
+    let mut memo = Memo::new(yell);
+    let result = memo.get(&expressions, &"root".to_string());
+    memo.report("monkeys");
+    Ok(result?)
+}
+
+pub fn part02(input: &str) -> Result<i64> {
+    let mut human_range = (i32::MIN as i64 / 2, i32::MAX as i64 * 2048);
+    let mut expressions = parse(input);
+        let (left_result, right_result) = match expressions["root"].clone() {
+            Expression::Expression(e) => (e.0, e.2),
+            _ => unreachable!()
+        };
+        expressions.insert("root".to_string(), Expression::Expression((left_result, '-', right_result)));
+        if explain::enabled() {
+            explain_equation(&expressions)?;
+        }
+        // only the monkeys listening to the human change between guesses, the rest stay memoized
+        let mut listening = Memo::new(depends_on_human);
+        let human_path = expressions.keys().filter(|name| listening.get(&expressions, name)).cloned().collect::<Vec<_>>();
+        let mut memo = Memo::new(yell);
+        let mut eval_with_stupid_human = |expressions: &mut Expressions, human: i64| {
+            expressions.insert("humn".to_string(), Expression::Value(human));
+            human_path.iter().for_each(|name| memo.forget(name));
+            memo.get(expressions, &"root".to_string()).map_err(|overflow| anyhow!("with the human yelling {}, {}", human, overflow))
+        };
+        let result = eval_with_stupid_human(&mut expressions, human_range.0)?;
+        if result > 0 { (human_range.0, human_range.1) = (human_range.1, human_range.0); }
+        let mut human: i64 = 0;
+        for _ in 0..100 {
+            human = (human_range.0 + human_range.1) / 2;
+            let result = eval_with_stupid_human(&mut expressions, human)?;
+            tracing::trace!(human, result, "bisection step");
+            if result == 0 {
+                tracing::debug!(human, "good job human!");
+                return Ok(human);
+            }
+            if result < 0 { human_range.0 = human; } else { human_range.1 = human; }
+        }
+    Ok(human)
+}
+
+struct Monkeys {
+    expressions: HashMap<String, Expression>,
+}
+
+impl Monkeys {
+    fn get(&self, name: &str) -> Result<&Expression> {
+        self.expressions.get(name).ok_or_else(|| anyhow!("no monkey named {}", name))
+    }
+}
+
+impl Session for Monkeys {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("eval <monkey>", "number the monkey yells"),
+            ("show <monkey>", "what the monkey yells"),
+            ("set <monkey> <value>", "make the monkey yell a number instead"),
+        ]
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String> {
+        match (command, args) {
+            ("eval", [name]) => {
+                self.get(name)?;
+                Ok(eval(&self.expressions, name)?.to_string())
+            }
+            ("show", [name]) => Ok(match self.get(name)? {
+                Expression::Value(i) => i.to_string(),
+                Expression::Expression((left, op, right)) => format!("{} {} {}", left, op, right),
+            }),
+            ("set", [name, value]) => {
+                self.get(name)?;
+                self.expressions.insert(name.to_string(), Expression::Value(value.parse()?));
+                Ok(format!("{}: {}", name, value))
+            }
+            _ => bail!("unknown command, try help"),
+        }
+    }
+}
+
+pub fn repl(input: &str) -> Result<Box<dyn Session>> {
+    Ok(Box::new(Monkeys { expressions: parse(input) }))
+}
@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::checkpoint;
+use crate::repl::Session;
+use crate::simulation::{Driver, Outcome, Simulation, Stop};
+
+fn nw(pos: &(i32, i32)) -> (i32, i32) {
+    (pos.0 - 1, pos.1 - 1)
+}
+fn n(pos: &(i32, i32)) -> (i32, i32) {
+    (pos.0 - 1, pos.1)
+}
+fn ne(pos: &(i32, i32)) -> (i32, i32) {
+    (pos.0 - 1, pos.1 + 1)
+}
+fn w(pos: &(i32, i32)) -> (i32, i32) {
+    (pos.0, pos.1 - 1)
+}
+fn e(pos: &(i32, i32)) -> (i32, i32) {
+    (pos.0, pos.1 + 1)
+}
+fn sw(pos: &(i32, i32)) -> (i32, i32) {
+    (pos.0 + 1, pos.1 - 1)
+}
+fn s(pos: &(i32, i32)) -> (i32, i32) {
+    (pos.0 + 1, pos.1)
+}
+fn se(pos: &(i32, i32)) -> (i32, i32) {
+    (pos.0 + 1, pos.1 + 1)
+}
+
+fn render_board(board: &HashMap<(i32, i32), u32>) -> String {
+    let (mut min_x, mut max_x, mut min_y, mut max_y) = (i32::MAX, i32::MIN, i32::MAX, i32::MIN);
+    for elve in board.iter() {
+        min_x = min_x.min(elve.0.0);
+        max_x = max_x.max(elve.0.0);
+        min_y = min_y.min(elve.0.1);
+        max_y = max_y.max(elve.0.1);
+    }
+    (min_x..=max_x)
+        .map(|i| (min_y..=max_y).map(|j| if board.contains_key(&(i, j)) { '#' } else { '.' }).collect::<String>())
+        .join("\n")
+}
+
+fn get_covered_ground(board: &HashMap<(i32, i32), u32>) -> u32 {
+    let (mut min_x, mut max_x, mut min_y, mut max_y) = (i32::MAX, i32::MIN, i32::MAX, i32::MIN);
+    for elve in board.iter() {
+        min_x = min_x.min(elve.0.0);
+        max_x = max_x.max(elve.0.0);
+        min_y = min_y.min(elve.0.1);
+        max_y = max_y.max(elve.0.1);
+    }
+    (max_x.abs_diff(min_x) + 1) * (max_y.abs_diff(min_y) + 1) - board.len() as u32
+}
+
+fn parse(input: &str) -> HashMap<(i32, i32), u32> {
+    let mut elve_id = 0_u32;
+    input
+        .trim()
+        .lines()
+        .enumerate()
+        .flat_map(|(i, line)| {
+            line.char_indices()
+                .filter_map(|(j, c)| match c {
+                    '#' => {
+                        let result = ((i as i32, j as i32), elve_id);
+                        elve_id += 1;
+                        Some(result)
+                    }
+                    _ => None,
+                })
+                .collect_vec()
+        })
+        .collect()
+}
+
+fn spread_out(board: &HashMap<(i32, i32), u32>, consideration_order: &[char]) -> HashMap<(i32, i32), u32> {
+    let mut propositions: HashMap<(i32, i32), u32> = HashMap::new();
+    let mut old_pos = vec![(0_i32, 0_i32); board.len()];
+    for elve in board.iter() {
+        let (pos, id) = elve;
+        old_pos[*id as usize] = *pos;
+        let neighbors = [nw(pos), n(pos), ne(pos), w(pos), e(pos), sw(pos), s(pos), se(pos)];
+        if !neighbors.iter().any(|n| board.contains_key(n)) {
+            propositions.insert(*pos, *id);
+            continue;
+        }
+        let mut considered_direction = consideration_order.iter().clone();
+        loop {
+            let direction = considered_direction.next();
+            match direction {
+                Some('N') => {
+                    if ![nw(pos), n(pos), ne(pos)].iter().any(|n| board.contains_key(n)) {
+                        if let std::collections::hash_map::Entry::Vacant(e) = propositions.entry(n(pos)) {
+                            e.insert(*id);
+                        } else {
+                            let old_elve = propositions.remove_entry(&n(pos)).unwrap();
+                            propositions.insert(old_pos[old_elve.1 as usize], old_elve.1);
+                            propositions.insert(*pos, *id);
+                        }
+                        break;
+                    }
+                },
+                Some('S') => {
+                    if ![sw(pos), s(pos), se(pos)].iter().any(|n| board.contains_key(n)) {
+                        if let std::collections::hash_map::Entry::Vacant(e) = propositions.entry(s(pos)) {
+                            e.insert(*id);
+                        } else {
+                            let old_elve = propositions.remove_entry(&s(pos)).unwrap();
+                            propositions.insert(old_pos[old_elve.1 as usize], old_elve.1);
+                            propositions.insert(*pos, *id);
+                        }
+                        break;
+                    }
+                },
+                Some('W') => {
+                    if ![nw(pos), w(pos), sw(pos)].iter().any(|n| board.contains_key(n)) {
+                        if let std::collections::hash_map::Entry::Vacant(e) = propositions.entry(w(pos)) {
+                            e.insert(*id);
+                        } else {
+                            let old_elve = propositions.remove_entry(&w(pos)).unwrap();
+                            propositions.insert(old_pos[old_elve.1 as usize], old_elve.1);
+                            propositions.insert(*pos, *id);
+                        }
+                        break;
+                    }
+                },
+                Some('E') => {
+                    if ![ne(pos), e(pos), se(pos)].iter().any(|n| board.contains_key(n)) {
+                        if let std::collections::hash_map::Entry::Vacant(e) = propositions.entry(e(pos)) {
+                            e.insert(*id);
+                        } else {
+                            let old_elve = propositions.remove_entry(&e(pos)).unwrap();
+                            propositions.insert(old_pos[old_elve.1 as usize], old_elve.1);
+                            propositions.insert(*pos, *id);
+                        }
+                        break;
+                    }
+                },
+                None => {
+                    propositions.insert(*pos, *id);
+                    break;
+                },
+                Some(_) => unreachable!()
+            }
+        }
+    }
+    propositions
+}
+
+// a step is one round of proposing and moving, the elves are done once nobody moves
+struct Grove {
+    board: HashMap<(i32, i32), u32>,
+    consideration_order: Vec<char>,
+    round: u32,
+}
+
+impl Grove {
+    fn new(input: &str) -> Self {
+        Grove { board: parse(input), consideration_order: vec!['N', 'S', 'W', 'E'], round: 0 }
+    }
+}
+
+impl Simulation for Grove {
+    type Key = ();
+
+    fn step(&mut self) -> bool {
+        tracing::trace!(round = self.round, "round");
+        let propositions = spread_out(&self.board, &self.consideration_order);
+        self.consideration_order.rotate_left(1);
+        self.round += 1;
+        let moved = propositions != self.board;
+        self.board = propositions;
+        moved
+    }
+
+    fn render(&self) -> String {
+        render_board(&self.board)
+    }
+}
+
+fn first_ten_rounds(input: &str) -> Result<HashMap<(i32, i32), u32>> {
+    let mut driver = Driver::new(Grove::new(input));
+    driver.run(Stop::Steps(10))?;
+    Ok(driver.simulation.board)
+}
+
+pub fn part01(input: &str) -> Result<u32> {
+    Ok(get_covered_ground(&first_ten_rounds(input)?))
+}
+
+pub fn render_round_10(input: &str) -> Result<String> {
+    Ok(render_board(&first_ten_rounds(input)?))
+}
+
+// part 2 state between rounds, json maps need string keys so the board is stored as a list
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    elves: Vec<((i32, i32), u32)>,
+    consideration_order: Vec<char>,
+    round: u32,
+}
+
+pub fn part02(input: &str) -> Result<u32> {
+    let grove = match checkpoint::resume::<Snapshot>()? {
+        Some(snapshot) => Grove { board: snapshot.elves.into_iter().collect(), consideration_order: snapshot.consideration_order, round: snapshot.round },
+        None => Grove::new(input),
+    };
+    // stepping by hand to save a checkpoint after every round
+    let mut driver = Driver::new(grove);
+    while driver.advance()? {
+        let grove = &driver.simulation;
+        checkpoint::save(grove.round as u64, || Snapshot {
+            elves: grove.board.iter().map(|(&pos, &id)| (pos, id)).collect(),
+            consideration_order: grove.consideration_order.clone(),
+            round: grove.round,
+        })?;
+    }
+    Ok(driver.simulation.round)
+}
+
+struct GroveSession {
+    driver: Driver<Grove>,
+}
+
+impl Session for GroveSession {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("step [n]", "spread out for n rounds (default 1) and show the grid"),
+            ("grid", "show the grid"),
+            ("replay <round>", "show the grid as it was after an earlier round"),
+            ("ground", "empty ground tiles in the bounding rectangle"),
+        ]
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String> {
+        let grove = &self.driver.simulation;
+        match (command, args) {
+            ("step", []) => self.execute("step", &["1"]),
+            ("step", [n]) => {
+                let target = self.driver.steps() + n.parse::<u64>()?;
+                if self.driver.run(Stop::Steps(target))? == Outcome::FixedPoint {
+                    return Ok(format!("no elf moved in round {}", self.driver.simulation.round));
+                }
+                self.execute("grid", &[])
+            }
+            ("grid", []) => Ok(format!("after round {}\n{}", grove.round, grove.render())),
+            ("replay", [round]) => match self.driver.history().get(round.parse::<usize>()?) {
+                Some(grid) => Ok(format!("after round {}\n{}", round, grid)),
+                None => bail!("only rounds 0 to {} were played", self.driver.steps()),
+            },
+            ("ground", []) => Ok(get_covered_ground(&grove.board).to_string()),
+            _ => bail!("unknown command, try help"),
+        }
+    }
+}
+
+pub fn repl(input: &str) -> Result<Box<dyn Session>> {
+    Ok(Box::new(GroveSession { driver: Driver::new(Grove::new(input)).with_history() }))
+}
@@ -0,0 +1,297 @@
+use anyhow::{bail, Result};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+use crate::explain;
+use crate::repl::Session;
+use crate::simulation::{Driver, Outcome, Simulation, Stop};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn symbol(&self) -> char {
+        match *self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+
+    // the move between two positions a minute apart, None when waiting
+    fn between(from: (i32, i32), to: (i32, i32)) -> Option<Direction> {
+        [Direction::Up, Direction::Down, Direction::Left, Direction::Right].into_iter().find(|direction| direction.value() == (to.0 - from.0, to.1 - from.1))
+    }
+
+    fn value(&self) -> (i32, i32) {
+        match *self {
+            Direction::Up => (-1, 0),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+            Direction::Right => (0, 1),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Blizzard {
+    x: i32,
+    y: i32,
+    direction: Direction,
+}
+
+#[derive(Debug)]
+struct Basin {
+    width: i32,
+    height: i32,
+    start: (i32, i32),
+    end: (i32, i32),
+    walls: HashSet<(i32, i32)>,
+    blizzards: Vec<Blizzard>,
+    map_cache: HashMap<i32, HashSet<(i32, i32)>>,
+}
+
+impl Basin {
+    fn find_shortest_path_time(
+        &mut self,
+        start: (i32, i32),
+        end: (i32, i32),
+        start_time: i32,
+    ) -> Result<i32> {
+        let _trip = tracing::debug_span!("trip", ?start, ?end, start_time).entered();
+        let trip = Trip { basin: self, time: start_time, reachable: HashSet::from([start]), came_from: explain::enabled().then(Vec::new) };
+        let mut driver = Driver::new(trip);
+        match driver.run(Stop::When(&|trip: &Trip| trip.reachable.contains(&end)))? {
+            Outcome::Predicate => {
+                if let Some(came_from) = &driver.simulation.came_from {
+                    let mut route = vec![end];
+                    for step in came_from.iter().rev() {
+                        route.push(step[route.last().unwrap()]);
+                    }
+                    let moves = route.iter().rev().tuple_windows().map(|(&from, &to)| Direction::between(from, to).map_or('.', |direction| direction.symbol())).collect::<String>();
+                    explain::line(|| format!("{:?} at minute {} to {:?} at minute {}: {}", start, start_time, end, driver.simulation.time, moves));
+                }
+                Ok(driver.simulation.time)
+            }
+            _ => Ok(-1),
+        }
+    }
+
+    fn map_at_time(&mut self, time: i32) -> HashSet<(i32, i32)> {
+        let t = time % ((self.width - 2) * (self.height - 2));
+        if let Some(cached_points) = self.map_cache.get(&t) {
+            return cached_points.clone();
+        }
+
+        let mut points: HashSet<(i32, i32)> = self.walls.clone();
+        points.extend(self.blizzards.iter().map(|blizzard| self.blizzard_at_time(blizzard, time)));
+        self.map_cache.insert(t, points.clone());
+        points
+    }
+
+    fn blizzard_at_time(&self, blizzard: &Blizzard, time: i32) -> (i32, i32) {
+        let x = positive_modulo(
+            blizzard.x - 1 + blizzard.direction.value().0 * time,
+            self.height - 2,
+        ) + 1;
+        let y = positive_modulo(
+            blizzard.y - 1 + blizzard.direction.value().1 * time,
+            self.width - 2,
+        ) + 1;
+        (x, y)
+    }
+
+    fn render(&self, time: i32) -> String {
+        let mut cells: HashMap<(i32, i32), Vec<char>> = HashMap::new();
+        for blizzard in &self.blizzards {
+            cells.entry(self.blizzard_at_time(blizzard, time)).or_default().push(blizzard.direction.symbol());
+        }
+        (0..self.height)
+            .map(|x| {
+                (0..self.width)
+                    .map(|y| match cells.get(&(x, y)) {
+                        _ if self.walls.contains(&(x, y)) => '#',
+                        Some(c) if c.len() == 1 => c[0],
+                        Some(c) => char::from_digit(c.len() as u32, 10).unwrap_or('*'),
+                        None => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn neighbors(&self, pos: (i32, i32), map: &HashSet<(i32, i32)>) -> Vec<(i32, i32)> {
+        let mut neighbors: Vec<(i32, i32)> = Vec::new();
+        for (dx, dy) in [(1, 0), (0, 1), (0, -1), (-1, 0), (0, 0)] {
+            let x = pos.0 + dx;
+            let y = pos.1 + dy;
+            if x < 0 || x >= self.height || y < 0 || y >= self.width {
+                continue;
+            }
+            let p = (x, y);
+            if !map.contains(&p) {
+                neighbors.push(p)
+            }
+        }
+        neighbors
+    }
+}
+
+type CameFrom = HashMap<(i32, i32), (i32, i32)>;
+
+// every position the expedition can be in, a step waits or moves one square as the blizzards move
+struct Trip<'a> {
+    basin: &'a mut Basin,
+    time: i32,
+    reachable: HashSet<(i32, i32)>,
+    // for every minute, where each reachable position was reached from, only kept when explaining
+    came_from: Option<Vec<CameFrom>>,
+}
+
+impl Simulation for Trip<'_> {
+    type Key = ();
+
+    fn step(&mut self) -> bool {
+        self.time += 1;
+        let map = self.basin.map_at_time(self.time);
+        let basin = &self.basin;
+        let moves = self.reachable.iter().flat_map(|&pos| basin.neighbors(pos, &map).into_iter().map(move |next| (next, pos)));
+        match &mut self.came_from {
+            Some(came_from) => {
+                let step: CameFrom = moves.collect();
+                self.reachable = step.keys().copied().collect();
+                came_from.push(step);
+            }
+            None => self.reachable = moves.map(|(next, _)| next).collect(),
+        }
+        tracing::trace!(time = self.time, reachable = self.reachable.len(), "minute");
+        !self.reachable.is_empty()
+    }
+
+    fn render(&self) -> String {
+        let valley = self.basin.render(self.time);
+        valley
+            .lines()
+            .enumerate()
+            .map(|(x, line)| line.char_indices().map(|(y, c)| if self.reachable.contains(&(x as i32, y as i32)) { 'E' } else { c }).collect::<String>())
+            .join("\n")
+    }
+}
+
+fn positive_modulo(x: i32, y: i32) -> i32 {
+    ((x % y) + y) % y
+}
+
+fn parse(input: &str) -> Basin {
+    let input: Vec<String> = input
+        .trim()
+        .lines()
+        .map(|line| line.to_string())
+        .collect();
+    let start_pos = (0, 1);
+    let num_rows = input.len() as i32;
+    let num_cols = input.first().unwrap().len() as i32;
+    let end_pos = (num_rows - 1, num_cols - 2);
+    let blizzards: Vec<Blizzard> = input
+        .iter()
+        .enumerate()
+        .flat_map(|(x, line)| {
+            line.char_indices().filter_map(move |(y, c)| match c {
+                '>' => Some(Blizzard {
+                    x: x as i32,
+                    y: y as i32,
+                    direction: Direction::Right,
+                }),
+                '<' => Some(Blizzard {
+                    x: x as i32,
+                    y: y as i32,
+                    direction: Direction::Left,
+                }),
+                'v' => Some(Blizzard {
+                    x: x as i32,
+                    y: y as i32,
+                    direction: Direction::Down,
+                }),
+                '^' => Some(Blizzard {
+                    x: x as i32,
+                    y: y as i32,
+                    direction: Direction::Up,
+                }),
+                _ => None,
+            })
+        })
+        .collect();
+    let mut walls = HashSet::new();
+    for x in 0..num_cols {
+        if x != start_pos.1 {
+            walls.insert((0, x));
+        }
+        if x != end_pos.1 {
+            walls.insert((num_rows - 1, x));
+        }
+    }
+    for y in 0..num_rows {
+        walls.insert((y, 0));
+        walls.insert((y, num_cols - 1));
+    }
+    Basin {
+        width: num_cols,
+        height: num_rows,
+        start: start_pos,
+        end: end_pos,
+        walls,
+        blizzards,
+        map_cache: HashMap::new(),
+    }
+}
+
+pub fn part01(input: &str) -> Result<i32> {
+    let mut basin = parse(input);
+    basin.find_shortest_path_time(basin.start, basin.end, 0)
+}
+
+pub fn part02(input: &str) -> Result<i32> {
+    let mut basin = parse(input);
+    let time = basin.find_shortest_path_time(basin.start, basin.end, 0)?;
+    let time = basin.find_shortest_path_time(basin.end, basin.start, time)?;
+    basin.find_shortest_path_time(basin.start, basin.end, time)
+}
+
+struct Expedition {
+    basin: Basin,
+    time: i32,
+}
+
+impl Session for Expedition {
+    fn commands(&self) -> &'static [(&'static str, &'static str)] {
+        &[
+            ("step [n]", "advance n minutes (default 1) and show the valley"),
+            ("grid", "show the valley"),
+            ("reach", "minute the goal is reached when leaving the start now"),
+        ]
+    }
+
+    fn execute(&mut self, command: &str, args: &[&str]) -> Result<String> {
+        match (command, args) {
+            ("step", []) => self.execute("step", &["1"]),
+            ("step", [n]) => {
+                self.time += n.parse::<i32>()?;
+                self.execute("grid", &[])
+            }
+            ("grid", []) => Ok(format!("minute {}\n{}", self.time, self.basin.render(self.time))),
+            ("reach", []) => Ok(self.basin.find_shortest_path_time(self.basin.start, self.basin.end, self.time)?.to_string()),
+            _ => bail!("unknown command, try help"),
+        }
+    }
+}
+
+pub fn repl(input: &str) -> Result<Box<dyn Session>> {
+    Ok(Box::new(Expedition { basin: parse(input), time: 0 }))
+}
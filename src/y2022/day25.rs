@@ -0,0 +1,48 @@
+use anyhow::Result;
+
+use crate::checked;
+use crate::runner::Answer;
+
+fn to_snafu(mut n: i64) -> String {
+    let mut digits: Vec<i8> = Vec::with_capacity(20);
+    while n > 0 {
+        let i = (n % 5) as i8;
+        n /= 5;
+        digits.push(i);
+    }
+    for i in 0..digits.len() {
+        if digits[i] > 2 {
+            let offset = digits[i] - 5;
+            digits[i+1] += 1;
+            digits[i] = offset;
+        }
+    }
+    digits.iter().rev().map(|d| match *d {
+        -2 => "=".to_string(),
+        -1 => "-".to_string(),
+        n => n.to_string()
+    })
+    .collect::<String>()
+
+}
+
+pub fn part01(input: &str) -> Result<String> {
+    let sum = input
+        .trim()
+        .lines()
+        .map(|line| line.chars().map(|c| match c {
+            '=' => -2,
+            '-' => -1,
+            n => n.to_digit(10).unwrap() as i64
+        }).rev().enumerate()
+             .try_fold(0, |acc, (pos, digit)| checked::add(acc, checked::mul(checked::pow(5_i64, pos as u32)?, digit)?))
+            )
+    .try_fold(0, |acc, number| checked::add(acc, number?))?;
+    let result = to_snafu(sum);
+    Ok(result)
+}
+
+// the last star is a gift for finishing the others, there is nothing to compute
+pub fn part02(_input: &str) -> Result<Answer> {
+    Ok(Answer::NotApplicable)
+}
@@ -0,0 +1,91 @@
+use crate::registry::Day;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+pub static DAYS: &[Day] = &[
+    day!(2022, 1, day01),
+    day!(2022, 2, day02),
+    day!(2022, 3, day03),
+    day!(2022, 4, day04),
+    Day { assumptions: &[assumption!("single-digit-stacks", day05::check_single_digit_stacks)], ..day!(2022, 5, day05) },
+    day!(2022, 6, day06),
+    day!(2022, 7, day07),
+    Day { assumptions: &[assumption!("square-grid", day08::check_square_grid)], ..day!(2022, 8, day08) },
+    day!(2022, 9, day09),
+    Day { renders: &[render!("crt", day10::render_crt)], ..day!(2022, 10, day10) },
+    day!(2022, 11, day11),
+    Day {
+        alternatives: &[alternative!(1, "bfs", day12::part01_bfs), alternative!(2, "bfs", day12::part02_bfs)],
+        generate: Some(day12::generate),
+        ..day!(2022, 12, day12)
+    },
+    day!(2022, 13, day13),
+    Day { renders: &[render!("cave", day14::render_cave)], ..day!(2022, 14, day14) },
+    Day {
+        alternatives: &[alternative!(1, "intervals", day15::part01_intervals)],
+        generate: Some(day15::generate),
+        ..day!(2022, 15, day15)
+    },
+    Day {
+        repl: Some(day16::repl),
+        alternatives: &[
+            alternative!(1, "dfs", day16::part01_dfs),
+            alternative!(2, "dfs", day16::part02_dfs),
+            alternative!(1, "sparse", day16::part01_sparse),
+            alternative!(2, "sparse", day16::part02_sparse),
+            alternative!(1, "dp", day16::part01_dp),
+        ],
+        generate: Some(day16::generate),
+        assumptions: &[assumption!("last-two-timeslots", day16::check_last_two_timeslots)],
+        ..day!(2022, 16, day16)
+    },
+    Day {
+        alternatives: &[alternative!(1, "cycles", day17::part01_cycles), alternative!(2, "sampling", day17::part02_sampling)],
+        generate: Some(day17::generate),
+        ..day!(2022, 17, day17)
+    },
+    day!(2022, 18, day18),
+    Day {
+        alternatives: &[
+            alternative!(1, "memo", day19::part01_memo),
+            alternative!(2, "memo", day19::part02_memo),
+            alternative!(1, "frontier", day19::part01_frontier),
+            alternative!(2, "frontier", day19::part02_frontier),
+        ],
+        ..day!(2022, 19, day19)
+    },
+    Day {
+        alternatives: &[alternative!(1, "linked", day20::part01_linked), alternative!(2, "linked", day20::part02_linked)],
+        generate: Some(day20::generate),
+        ..day!(2022, 20, day20)
+    },
+    Day { repl: Some(day21::repl), ..day!(2022, 21, day21) },
+    Day { assumptions: &[assumption!("net-layout", day22::check_net_layout)], ..day!(2022, 22, day22) },
+    Day { repl: Some(day23::repl), renders: &[render!("round-10", day23::render_round_10)], ..day!(2022, 23, day23) },
+    Day { repl: Some(day24::repl), ..day!(2022, 24, day24) },
+    day!(2022, 25, day25),
+];
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use libaoc::batch::{self, Verdict};
+use libaoc::ledger::Ledger;
+use libaoc::registry;
+use libaoc::runner::{Answer, RunOptions};
+
+// a fresh batch directory for day 8, one square and one ragged grid
+fn team_inputs(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-batch-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("alice.input"), "30373\n25512\n65332\n33549\n35390\n").unwrap();
+    std::fs::write(dir.join("bob.input"), "303\n255\n").unwrap();
+    std::fs::write(dir.join("notes.txt"), "not an input").unwrap();
+    std::fs::write(batch::ledger_path(&dir), "alice 1 21\nalice 2 7\n").unwrap();
+    dir
+}
+
+#[test]
+fn finds_the_named_inputs() {
+    let dir = team_inputs("names");
+    assert_eq!(batch::batch_inputs(&dir).unwrap(), vec!["alice", "bob"]);
+}
+
+#[test]
+fn compares_against_the_ledger_of_the_batch() {
+    let dir = team_inputs("ledger");
+    let day = registry::get(2022, 8).unwrap();
+    let ledger = Ledger::read(&batch::ledger_path(&dir)).unwrap();
+    let report = batch::verify_input(day, &dir, "alice", &ledger, &RunOptions::default()).unwrap();
+    let verdicts: Vec<&Verdict> = report.parts.iter().map(|(_, verdict)| verdict).collect();
+    assert_eq!(verdicts, vec![&Verdict::Matches, &Verdict::Differs(Answer::Integer(7))]);
+    assert_eq!(report.failures(), 1);
+    assert_eq!(report.broken().count(), 0);
+}
+
+#[test]
+fn reports_broken_assumptions_per_input() {
+    let dir = team_inputs("assumptions");
+    let day = registry::get(2022, 8).unwrap();
+    let ledger = Ledger::read(&batch::ledger_path(&dir)).unwrap();
+    let report = batch::verify_input(day, &dir, "bob", &ledger, &RunOptions::default()).unwrap();
+    assert!(report.parts.iter().all(|(_, verdict)| *verdict == Verdict::Unrecorded));
+    assert_eq!(report.broken().map(|finding| finding.assumption).collect::<Vec<_>>(), vec!["square-grid"]);
+}
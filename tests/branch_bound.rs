@@ -0,0 +1,88 @@
+use libaoc::branch_bound::{maximize, maximize_traced, Problem, Strategy};
+use libaoc::differential::Rng;
+
+// 0/1 knapsack, items are decided in order
+struct Knapsack {
+    items: Vec<(u64, u64)>,
+    capacity: u64,
+}
+
+#[derive(Debug, Clone)]
+struct Packing {
+    next: usize,
+    weight: u64,
+    value: u64,
+}
+
+impl Problem for Knapsack {
+    type State = Packing;
+    type Key = usize;
+
+    fn successors(&self, packing: &Packing, next: &mut Vec<Packing>) {
+        let Some(&(weight, value)) = self.items.get(packing.next) else { return };
+        if packing.weight + weight <= self.capacity {
+            next.push(Packing { next: packing.next + 1, weight: packing.weight + weight, value: packing.value + value });
+        }
+        next.push(Packing { next: packing.next + 1, ..*packing });
+    }
+
+    fn objective(&self, packing: &Packing) -> u64 {
+        packing.value
+    }
+
+    fn upper_bound(&self, packing: &Packing) -> u64 {
+        packing.value + self.items[packing.next..].iter().map(|&(_, value)| value).sum::<u64>()
+    }
+
+    fn dominance_key(&self, packing: &Packing) -> Option<usize> {
+        Some(packing.next)
+    }
+
+    fn dominates(&self, a: &Packing, b: &Packing) -> bool {
+        a.weight <= b.weight && a.value >= b.value
+    }
+}
+
+fn brute_force(knapsack: &Knapsack) -> u64 {
+    (0..1u32 << knapsack.items.len())
+        .filter_map(|chosen| {
+            let items = knapsack.items.iter().enumerate().filter(|(i, _)| chosen & 1 << i != 0);
+            let (weight, value) = items.fold((0, 0), |(w, v), (_, &(weight, value))| (w + weight, v + value));
+            (weight <= knapsack.capacity).then_some(value)
+        })
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn finds_the_optimum_with_either_strategy() {
+    let mut rng = Rng::new(7);
+    for _ in 0..50 {
+        let items = (0..rng.range(1, 12)).map(|_| (rng.range(1, 20) as u64, rng.range(0, 30) as u64)).collect();
+        let knapsack = Knapsack { items, capacity: rng.range(0, 60) as u64 };
+        let expected = brute_force(&knapsack);
+        for strategy in [Strategy::DepthFirst, Strategy::BestFirst] {
+            let start = Packing { next: 0, weight: 0, value: 0 };
+            let solution = maximize(&knapsack, start, strategy).unwrap();
+            assert_eq!(solution.value, expected, "{:?} {:?}", strategy, knapsack.items);
+            assert_eq!(solution.state.value, expected);
+        }
+    }
+}
+
+
+#[test]
+fn traces_the_way_to_the_optimum() {
+    let mut rng = Rng::new(11);
+    for _ in 0..50 {
+        let items = (0..rng.range(1, 12)).map(|_| (rng.range(1, 20) as u64, rng.range(0, 30) as u64)).collect();
+        let knapsack = Knapsack { items, capacity: rng.range(0, 60) as u64 };
+        let start = Packing { next: 0, weight: 0, value: 0 };
+        let solution = maximize_traced(&knapsack, start, Strategy::DepthFirst).unwrap();
+        assert_eq!(solution.value, brute_force(&knapsack));
+        let path = solution.state;
+        assert_eq!(path.first().unwrap().next, 0);
+        assert_eq!(path.last().unwrap().value, solution.value);
+        assert!(path.windows(2).all(|step| step[1].next == step[0].next + 1));
+    }
+}
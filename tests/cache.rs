@@ -0,0 +1,58 @@
+use libaoc::cache::{answer_features, Cache, SHARED_SOURCES};
+use libaoc::registry;
+use libaoc::runner::{self, RunOptions};
+
+const ELVES: &str = "1000\n2000\n3000\n\n4000\n";
+
+fn cache_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-cache-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn hits_with_the_same_sources() {
+    let dir = cache_dir("same");
+    let day = registry::get(2022, 1).unwrap();
+    let record = runner::solve(day, 1, "inline", ELVES, &RunOptions::default());
+    Cache::with(dir.clone(), SHARED_SOURCES, &answer_features()).unwrap().put(day, 1, ELVES, &record).unwrap();
+    let cached = Cache::with(dir, SHARED_SOURCES, &answer_features()).unwrap().get(day, 1, ELVES).unwrap();
+    assert_eq!(cached.answer, record.answer);
+}
+
+#[test]
+fn misses_when_a_shared_module_changes() {
+    let dir = cache_dir("changed");
+    let day = registry::get(2022, 1).unwrap();
+    let record = runner::solve(day, 1, "inline", ELVES, &RunOptions::default());
+    Cache::with(dir.clone(), SHARED_SOURCES, &answer_features()).unwrap().put(day, 1, ELVES, &record).unwrap();
+    let mut changed = SHARED_SOURCES.to_vec();
+    let edited = format!("{}\n// edited\n", changed[0].1);
+    changed[0].1 = &edited;
+    assert!(Cache::with(dir, &changed, &answer_features()).unwrap().get(day, 1, ELVES).is_none());
+}
+
+#[test]
+fn misses_when_the_features_change() {
+    let dir = cache_dir("features");
+    let day = registry::get(2022, 1).unwrap();
+    let record = runner::solve(day, 1, "inline", ELVES, &RunOptions::default());
+    Cache::with(dir.clone(), SHARED_SOURCES, &[]).unwrap().put(day, 1, ELVES, &record).unwrap();
+    assert!(Cache::with(dir.clone(), SHARED_SOURCES, &[]).unwrap().get(day, 1, ELVES).is_some());
+    assert!(Cache::with(dir.clone(), SHARED_SOURCES, &["checked-arith"]).unwrap().get(day, 1, ELVES).is_none());
+    assert!(Cache::with(dir, SHARED_SOURCES, &["parallel"]).unwrap().get(day, 1, ELVES).is_none());
+}
+
+#[test]
+fn keys_on_the_features_that_change_answers() {
+    assert_eq!(answer_features().contains(&"checked-arith"), cfg!(feature = "checked-arith"));
+    assert_eq!(answer_features().contains(&"parallel"), cfg!(feature = "parallel"));
+}
+
+#[test]
+fn lists_every_library_module() {
+    let modules = std::fs::read_dir("src").unwrap().map(|entry| entry.unwrap().file_name().into_string().unwrap());
+    for module in modules.filter(|name| name.ends_with(".rs") && name != "main.rs") {
+        assert!(SHARED_SOURCES.iter().any(|(name, _)| *name == module), "src/{} is not in SHARED_SOURCES", module);
+    }
+}
@@ -0,0 +1,22 @@
+use libaoc::checked::{self, Cast};
+
+#[test]
+fn computes_like_the_operators() {
+    assert_eq!(checked::add(2_u32, 3), Ok(5));
+    assert_eq!(checked::sub(2_i64, 3), Ok(-1));
+    assert_eq!(checked::mul(-4_i32, 3), Ok(-12));
+    assert_eq!(checked::div(7_u64, 2), Ok(3));
+    assert_eq!(checked::pow(5_i64, 3), Ok(125));
+    assert_eq!(Cast::<i32>::cast(7_u32), Ok(7));
+}
+
+#[cfg(feature = "checked-arith")]
+#[test]
+fn reports_the_operands_that_overflowed() {
+    assert_eq!(checked::mul(70000_u32, 70000).unwrap_err().to_string(), "70000 * 70000 overflows u32");
+    assert_eq!(checked::div(1_i64, 0).unwrap_err().to_string(), "1 / 0 divides by zero");
+    assert_eq!(checked::div(i64::MIN, -1).unwrap_err().to_string(), format!("{} / -1 overflows i64", i64::MIN));
+    assert_eq!(checked::pow(5_i64, 30).unwrap_err().to_string(), "5 ^ 30 overflows i64");
+    assert_eq!(Cast::<i32>::cast(u32::MAX).unwrap_err().to_string(), "4294967295 does not fit in i32");
+    assert_eq!(checked::add(i64::MAX, 1).unwrap_err().at("monkey root").to_string(), format!("monkey root: {} + 1 overflows i64", i64::MAX));
+}
@@ -0,0 +1,91 @@
+use libaoc::cache;
+use libaoc::checkpoint::{self, Snapshot};
+use libaoc::context::{CheckpointOptions, Context};
+use libaoc::registry;
+use libaoc::runner::{self, Answer, RunOptions};
+use serde_json::Value;
+
+fn checkpoint_dir(name: &str) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc-checkpoints-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+fn options(dir: &std::path::Path, every: Option<u64>, resume: bool) -> RunOptions {
+    RunOptions { checkpoints: Some(CheckpointOptions { dir: dir.to_path_buf(), every, resume }), ..RunOptions::default() }
+}
+
+// where a part keeps its snapshot for input
+fn part_snapshot_path(dir: &std::path::Path, day: u32, part: u32, input: &str) -> std::path::PathBuf {
+    let context = Context { year: 2022, day, part, input_hash: cache::input_hash(input), ..Context::default() };
+    checkpoint::path(&context, &CheckpointOptions { dir: dir.to_path_buf(), every: None, resume: true })
+}
+
+fn snapshot_path(dir: &std::path::Path, input: &str) -> std::path::PathBuf {
+    part_snapshot_path(dir, 11, 2, input)
+}
+
+#[test]
+fn resumes_day_11_from_a_saved_round() {
+    let dir = checkpoint_dir("resume");
+    let day = registry::get(2022, 11).unwrap();
+    let input = std::fs::read_to_string(runner::input_path(2022, 11, "example")).unwrap();
+    // 10000 rounds are not a multiple of 3000, the last snapshot is what a run stopped after round 9000 leaves behind
+    let first = runner::solve(day, 2, "example", &input, &options(&dir, Some(3000), false));
+    assert_eq!(first.answer, Some(Answer::Integer(2713310158)));
+    let path = snapshot_path(&dir, &input);
+    let snapshot: Snapshot<Value> = checkpoint::load(&path).unwrap();
+    assert_eq!(snapshot.step, 9000);
+    assert_eq!(snapshot.state["round"], 9000);
+
+    let resumed = runner::solve(day, 2, "example", &input, &options(&dir, None, true));
+    assert_eq!(resumed.answer, first.answer);
+
+    // a snapshot claiming the game is over is taken at its word, so the answer above came from round 9000
+    let mut counts: Vec<u64> = snapshot.state["inspected_items"].as_array().unwrap().iter().map(|n| n.as_u64().unwrap()).collect();
+    let mut state = snapshot.state;
+    state["round"] = 10000.into();
+    std::fs::write(&path, serde_json::to_string(&Snapshot { state, ..snapshot }).unwrap()).unwrap();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+    let finished = runner::solve(day, 2, "example", &input, &options(&dir, None, true));
+    assert_eq!(finished.answer, Some(Answer::Integer((counts[0] * counts[1]) as i64)));
+}
+
+#[test]
+fn ignores_a_checkpoint_for_another_input() {
+    let dir = checkpoint_dir("other");
+    let day = registry::get(2022, 11).unwrap();
+    let input = std::fs::read_to_string(runner::input_path(2022, 11, "example")).unwrap();
+    runner::solve(day, 2, "example", &input, &options(&dir, Some(3000), false));
+    assert!(snapshot_path(&dir, &input).exists());
+
+    let other = input.replacen("Starting items: 79, 98", "Starting items: 79, 98, 60", 1);
+    let expected = runner::solve(day, 2, "other", &other, &RunOptions::default());
+    assert_ne!(expected.answer, Some(Answer::Integer(2713310158)));
+    let resumed = runner::solve(day, 2, "other", &other, &options(&dir, None, true));
+    assert_eq!(resumed.answer, expected.answer);
+    assert!(!snapshot_path(&dir, &other).exists());
+}
+
+#[test]
+fn resumes_day_19_after_the_finished_blueprints() {
+    let dir = checkpoint_dir("geodes");
+    let day = registry::get(2022, 19).unwrap();
+    let input = std::fs::read_to_string(runner::input_path(2022, 19, "example")).unwrap();
+    let first = runner::solve(day, 1, "example", &input, &options(&dir, Some(1), false));
+    assert_eq!(first.answer, Some(Answer::Integer(33)));
+    let path = part_snapshot_path(&dir, 19, 1, &input);
+    let snapshot: Snapshot<Value> = checkpoint::load(&path).unwrap();
+    assert_eq!(snapshot.step, 2);
+    assert_eq!(snapshot.state[0][0], 9);
+    assert_eq!(snapshot.state[1][0], 12);
+
+    let resumed = runner::solve(day, 1, "example", &input, &options(&dir, None, true));
+    assert_eq!(resumed.answer, first.answer);
+
+    // only the first blueprint finished, with a made up count the second one is searched again
+    let state = Value::Array(vec![serde_json::json!([5, []])]);
+    std::fs::write(&path, serde_json::to_string(&Snapshot { step: 1, state, ..snapshot }).unwrap()).unwrap();
+    let partial = runner::solve(day, 1, "example", &input, &options(&dir, None, true));
+    assert_eq!(partial.answer, Some(Answer::Integer(5 + 2 * 12)));
+}
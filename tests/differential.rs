@@ -0,0 +1,59 @@
+use itertools::Itertools;
+use libaoc::differential::{agree, compare, parts_with_alternatives, Rng};
+use libaoc::registry::{self, Day};
+use libaoc::runner;
+
+// generated inputs per part, the seeds are fixed so a failure reproduces with `aoc diff --seed`
+const GENERATED: u64 = 10;
+
+// too slow for the alternatives of an unoptimized build, compared by the release only test below
+const SLOW: &[(u32, u32)] = &[(19, 2)];
+
+fn assert_agree(day: &Day, part: u32, label: &str, input: &str) {
+    let outcomes = compare(day, part, input);
+    let answers = outcomes.iter().map(|outcome| format!("{} {:?}", outcome.name, outcome.answer)).join(", ");
+    assert!(agree(&outcomes), "{} day{:02} part {} [{}] disagrees: {}", day.year, day.day, part, label, answers);
+}
+
+fn examples(day: &Day) -> Vec<(String, String)> {
+    let names = runner::available_inputs(day.year, day.day).unwrap();
+    let examples = names.into_iter().filter(|name| name.starts_with("example"));
+    examples.map(|name| {
+        let input = std::fs::read_to_string(runner::input_path(day.year, day.day, &name)).unwrap();
+        (name, input)
+    }).collect()
+}
+
+#[test]
+fn alternatives_agree_on_the_examples() {
+    for day in registry::all() {
+        for part in parts_with_alternatives(day).into_iter().filter(|&part| !SLOW.contains(&(day.day, part))) {
+            for (name, input) in examples(day) {
+                assert_agree(day, part, &name, &input);
+            }
+        }
+    }
+}
+
+#[test]
+fn alternatives_agree_on_generated_inputs() {
+    for day in registry::all() {
+        let Some(generate) = day.generate else { continue };
+        for part in parts_with_alternatives(day) {
+            for seed in 0..GENERATED {
+                assert_agree(day, part, &format!("seed {}", seed), &generate(&mut Rng::new(seed)));
+            }
+        }
+    }
+}
+
+#[test]
+#[cfg_attr(debug_assertions, ignore = "minutes without optimizations, run with cargo test --release")]
+fn slow_alternatives_agree_on_the_examples() {
+    for &(day, part) in SLOW {
+        let day = registry::get(2022, day).unwrap();
+        for (name, input) in examples(day) {
+            assert_agree(day, part, &name, &input);
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use libaoc::differential::Rng;
+use libaoc::dp::{DpTable, Mask, MaskTable, Memo, Storage, Table};
+
+#[test]
+fn dense_and_sparse_tables_agree() {
+    let shape = [4, 5, 6];
+    let mut dense = DpTable::new(Storage::Dense, &shape);
+    let mut sparse = DpTable::new(Storage::Sparse, &shape);
+    let mut rng = Rng::new(3);
+    for _ in 0..200 {
+        let key = (rng.range(0, 3) as usize, rng.range(0, 4) as usize, rng.range(0, 5) as usize);
+        match rng.chance(1, 4) {
+            true => {
+                dense.remove(&key);
+                sparse.remove(&key);
+            }
+            false => {
+                let value = rng.range(1, 1000) as u32;
+                dense.set(key, value);
+                sparse.set(key, value);
+            }
+        }
+    }
+    assert_eq!(dense.len(), sparse.len());
+    for i in 0..4 {
+        for j in 0..5 {
+            for k in 0..6 {
+                assert_eq!(dense.get(&(i, j, k)), sparse.get(&(i, j, k)));
+                assert_eq!(dense.at((i, j, k)), sparse.at((i, j, k)));
+            }
+        }
+    }
+}
+
+#[test]
+fn removed_cells_read_as_default() {
+    let mut table = DpTable::new(Storage::Dense, &[3, 3]);
+    table.set((1, 2), 7u32);
+    table.remove(&(1, 2));
+    assert_eq!(table.at((1, 2)), 0);
+    assert!(table.is_empty());
+}
+
+fn fib(memo: &mut Memo<u64, u64, ()>, _: &(), &n: &u64) -> u64 {
+    match n {
+        0 | 1 => n,
+        n => memo.get(&(), &(n - 1)) + memo.get(&(), &(n - 2)),
+    }
+}
+
+#[test]
+fn memo_computes_every_key_once() {
+    let mut memo = Memo::new(fib);
+    assert_eq!(memo.get(&(), &90), 2880067194370816120);
+    assert_eq!(memo.table().len(), 91);
+    memo.forget(&90);
+    assert_eq!(memo.table().len(), 90);
+    assert_eq!(memo.get(&(), &90), 2880067194370816120);
+}
+
+#[test]
+fn mask_ones_lists_the_elements_in_order() {
+    assert_eq!(Mask(0b1010_0110).ones().collect::<Vec<_>>(), vec![1, 2, 5, 7]);
+    assert_eq!(Mask(0).ones().count(), 0);
+    assert_eq!(Mask::full(64).ones().count(), 64);
+    assert_eq!(Mask::full(3).with(5).without(0).ones().collect::<Vec<_>>(), vec![1, 2, 5]);
+}
+
+#[test]
+fn fold_subsets_combines_all_subsets() {
+    let mut table = MaskTable::new(4, 0u32);
+    for mask in table.masks().collect::<Vec<_>>() {
+        table[mask] = mask.0 as u32;
+    }
+    table.fold_subsets(|a, b| *a.max(b));
+    for mask in table.masks() {
+        let best = (0..16u64).filter(|sub| sub & !mask.0 == 0).max().unwrap() as u32;
+        assert_eq!(table[mask], best);
+    }
+    let mut sums = MaskTable::new(3, 1u32);
+    sums.fold_subsets(|a, b| a + b);
+    assert_eq!(sums[Mask(0b111)], 8);
+    assert_eq!(sums[Mask(0b101)], 4);
+}
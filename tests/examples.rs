@@ -0,0 +1,30 @@
+use libaoc::examples::{parse, Article};
+
+const PAGE: &str = include_str!("fixtures/2022-day01.html");
+
+#[test]
+fn finds_the_example_and_both_answers_on_a_saved_page() {
+    let articles = parse(PAGE);
+    assert_eq!(articles.len(), 2);
+    let example = std::fs::read_to_string("data/2022/day01.example").unwrap();
+    assert_eq!(articles[0].blocks.len(), 1);
+    assert_eq!(articles[0].blocks[0].text, example);
+    assert!(articles[0].blocks[0].lead.ends_with("with the following list:"));
+    assert_eq!(articles[0].answer.as_deref(), Some("24000"));
+    assert!(articles[1].blocks.is_empty());
+    assert_eq!(articles[1].answer.as_deref(), Some("45000"));
+}
+
+#[test]
+fn takes_the_last_emphasized_answer() {
+    let html = "<article><p>first <code><em>7</em></code>, then <em>11</em> and <em><code>abc</code></em></p></article>";
+    assert_eq!(parse(html)[0].answer.as_deref(), Some("abc"));
+    let html = "<article><p>first <em><code>abc</code></em>, then <em> 11 </em></p></article>";
+    assert_eq!(parse(html)[0].answer.as_deref(), Some("11"));
+}
+
+#[test]
+fn ignores_emphasized_prose() {
+    let html = "<article><p>carried by <em>five</em> Elves, <em>how many Calories?</em></p></article>";
+    assert_eq!(parse(html), vec![Article { blocks: Vec::new(), answer: None }]);
+}
@@ -0,0 +1,50 @@
+use libaoc::registry;
+use libaoc::runner::{self, RunOptions, Status};
+
+const ELVES: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+#[test]
+fn explains_the_answer_when_asked() {
+    let day = registry::get(2022, 1).unwrap();
+    let record = runner::solve(day, 2, "inline", ELVES, &RunOptions { explain: true, ..RunOptions::default() });
+    assert_eq!(record.status, Status::Ok);
+    assert_eq!(record.explanation, vec!["#1 is elf 4 with 24000 calories", "#2 is elf 3 with 11000 calories", "#3 is elf 5 with 10000 calories"]);
+}
+
+#[test]
+fn stays_quiet_otherwise() {
+    let day = registry::get(2022, 1).unwrap();
+    let record = runner::solve(day, 2, "inline", ELVES, &RunOptions::default());
+    assert!(record.explanation.is_empty());
+    assert!(!serde_json::to_string(&record).unwrap().contains("explanation"));
+}
+
+#[test]
+fn solves_the_equation_for_the_human() {
+    let day = registry::get(2022, 21).unwrap();
+    let input = std::fs::read_to_string(runner::input_path(2022, 21, "example")).unwrap();
+    let record = runner::solve(day, 2, "example", &input, &RunOptions { explain: true, ..RunOptions::default() });
+    assert_eq!(record.explanation.first().unwrap(), "root = pppw - sjmn must be 0 with sjmn = 150, so pppw = 150");
+    assert!(record.explanation.last().unwrap().ends_with("so humn = 301"));
+}
+
+#[test]
+fn explains_the_walk_the_search_found() {
+    let day = registry::get(2022, 16).unwrap();
+    let input = std::fs::read_to_string(runner::input_path(2022, 16, "example")).unwrap();
+    let record = runner::solve(day, 1, "example", &input, &RunOptions { explain: true, ..RunOptions::default() });
+    assert_eq!(record.answer.unwrap().to_line(), "1651");
+    assert_eq!(record.explanation.first().unwrap(), "minute 2, you: open DD (20 x 28 = 560), route AA -> DD");
+    assert_eq!(record.explanation.len(), 6);
+}
+
+#[test]
+fn explains_the_plans_behind_the_answer() {
+    let day = registry::get(2022, 19).unwrap();
+    let input = std::fs::read_to_string(runner::input_path(2022, 19, "example")).unwrap();
+    let record = runner::solve(day, 1, "example", &input, &RunOptions { explain: true, ..RunOptions::default() });
+    assert_eq!(record.answer.unwrap().to_line(), "33");
+    assert_eq!(record.explanation.len(), 2);
+    assert!(record.explanation[0].starts_with("blueprint 1: 9 geodes, builds in minute "));
+    assert!(record.explanation[1].starts_with("blueprint 2: 12 geodes, builds in minute "));
+}
@@ -0,0 +1,43 @@
+use itertools::Itertools;
+use libaoc::grid::Grid;
+
+const MAP: &str = "ab#\n.cd\n";
+
+#[test]
+fn parses_rows_and_columns() {
+    let grid = Grid::parse(MAP, |c| c).unwrap();
+    assert_eq!((grid.width, grid.height), (3, 2));
+    assert_eq!(grid[(0, 2)], '#');
+    assert_eq!(grid[(1, 0)], '.');
+    assert_eq!(grid.get((2, 0)), None);
+    assert_eq!(grid.get((0, 3)), None);
+    assert_eq!(grid.to_string(), MAP);
+}
+
+#[test]
+fn rejects_rows_of_different_width() {
+    let error = Grid::parse("abc\nab\n", |c| c).unwrap_err();
+    assert_eq!(error.to_string(), "line 2 has a different width");
+}
+
+#[test]
+fn finds_and_maps_cells() {
+    let mut grid = Grid::parse(MAP, |c| c).unwrap();
+    assert_eq!(grid.find(|&c| c == 'c'), Some((1, 1)));
+    assert_eq!(grid.find(|&c| c == 'z'), None);
+    grid[(1, 1)] = 'z';
+    assert_eq!(grid.find(|&c| c == 'z'), Some((1, 1)));
+    let walls = grid.map(|&c| c == '#');
+    assert_eq!(walls.iter().filter(|(_, &wall)| wall).map(|(pos, _)| pos).collect_vec(), [(0, 2)]);
+    assert_eq!(grid.positions().collect_vec(), [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+}
+
+#[test]
+fn neighbours_stay_inside_the_grid() {
+    let grid = Grid::parse("...\n...\n...\n", |c| c).unwrap();
+    assert_eq!(grid.neighbours4((0, 0)).sorted().collect_vec(), [(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours4((1, 1)).sorted().collect_vec(), [(0, 1), (1, 0), (1, 2), (2, 1)]);
+    assert_eq!(grid.neighbours8((0, 0)).sorted().collect_vec(), [(0, 1), (1, 0), (1, 1)]);
+    assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    assert_eq!(grid.neighbours8((2, 2)).sorted().collect_vec(), [(1, 1), (1, 2), (2, 1)]);
+}
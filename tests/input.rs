@@ -0,0 +1,65 @@
+use libaoc::input::normalize;
+use libaoc::ledger::Ledger;
+use libaoc::registry;
+use libaoc::runner::{self, RunOptions, Status};
+
+const TEXT: &str = "1000\n2000\n\n3000\n";
+
+type Variant = (&'static str, fn(&str) -> String);
+
+#[test]
+fn leaves_normal_input_alone() {
+    assert!(matches!(normalize(TEXT), std::borrow::Cow::Borrowed(_)));
+    assert_eq!(normalize(""), "");
+}
+
+#[test]
+fn converts_crlf() {
+    assert_eq!(normalize("1000\r\n2000\r\n\r\n3000\r\n"), TEXT);
+}
+
+#[test]
+fn strips_the_bom() {
+    assert_eq!(normalize("\u{feff}1000\n2000\n\n3000\n"), TEXT);
+}
+
+#[test]
+fn strips_trailing_whitespace() {
+    assert_eq!(normalize("1000  \n2000\t\n \n3000 \n"), TEXT);
+}
+
+#[test]
+fn ends_with_exactly_one_newline() {
+    assert_eq!(normalize("1000\n2000\n\n3000"), TEXT);
+    assert_eq!(normalize("1000\n2000\n\n3000\n\n\n"), TEXT);
+}
+
+#[test]
+fn keeps_leading_whitespace() {
+    assert_eq!(normalize("    [D]\n[N] [C]\r\n"), "    [D]\n[N] [C]\n");
+}
+
+// the examples of days that split on blank lines or lines, saved in every way we know of
+#[test]
+fn examples_solve_in_every_variant() {
+    let variants: [Variant; 5] = [
+        ("crlf", |text| text.replace('\n', "\r\n")),
+        ("bom", |text| format!("\u{feff}{}", text)),
+        ("trailing whitespace", |text| text.replace('\n', " \t\n")),
+        ("no final newline", |text| text.trim_end().to_string()),
+        ("extra blank lines", |text| format!("{}\n\n", text)),
+    ];
+    for day in [1, 2, 3, 5, 7, 8, 9, 11, 13] {
+        let day = registry::get(2022, day).unwrap();
+        let example = std::fs::read_to_string(runner::input_path(day.year, day.day, "example")).unwrap();
+        let ledger = Ledger::load(day.year, day.day).unwrap();
+        for part in 1..=2 {
+            let Some(expected) = ledger.get("example", part) else { continue };
+            for (name, variant) in variants {
+                let record = runner::solve(day, part, name, &variant(&example), &RunOptions::default());
+                assert_eq!(record.status, Status::Ok, "day{:02} part {} with {}: {:?}", day.day, part, name, record.error);
+                assert_eq!(record.answer.as_ref(), Some(expected), "day{:02} part {} with {}", day.day, part, name);
+            }
+        }
+    }
+}
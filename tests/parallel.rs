@@ -0,0 +1,28 @@
+use libaoc::context::{self, Context};
+use libaoc::parallel;
+
+#[test]
+fn keeps_the_order_of_the_items() {
+    let items = (0..1000).collect::<Vec<u64>>();
+    assert_eq!(parallel::map(&items, |i| i * i), items.iter().map(|i| i * i).collect::<Vec<_>>());
+}
+
+#[test]
+fn finds_the_first_match_in_order() {
+    let items = (0..1000).collect::<Vec<u64>>();
+    assert_eq!(parallel::find_map_first(&items, |&i| (i % 97 == 96).then_some(i)), Some(96));
+    assert_eq!(parallel::find_map_first(&items, |&i| (i > 1000).then_some(i)), None);
+}
+
+#[test]
+fn takes_the_max_over_the_range() {
+    assert_eq!(parallel::max(0..100, |i| (i * 37) % 101), Some(100));
+    assert_eq!(parallel::max(5..5, |i| i), None);
+}
+
+#[test]
+fn workers_see_the_context() {
+    let context = Context { year: 2022, day: 19, ..Context::default() };
+    let days = context::scope(context, || parallel::map(&[1, 2, 3], |_| context::with(|context| context.map(|context| context.day))));
+    assert_eq!(days, vec![Some(19); 3]);
+}
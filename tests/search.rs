@@ -0,0 +1,41 @@
+use libaoc::search::{bfs, bfs_distances};
+
+// a line 0 - 1 - ... - 9 and a separate 20 - 21
+fn line(&n: &u32) -> Vec<u32> {
+    match n {
+        0 => vec![1],
+        9 => vec![8],
+        20 => vec![21],
+        21 => vec![20],
+        n => vec![n - 1, n + 1],
+    }
+}
+
+#[test]
+fn finds_the_closest_goal() {
+    assert_eq!(bfs([0], line, |&n| n == 7), Some((7, 7)));
+    assert_eq!(bfs([0], line, |&n| n >= 3), Some((3, 3)));
+    assert_eq!(bfs([4], line, |&n| n == 4), Some((4, 0)));
+}
+
+#[test]
+fn searches_from_all_starts_at_once() {
+    assert_eq!(bfs([0, 9], line, |&n| n == 7), Some((7, 2)));
+    assert_eq!(bfs([9, 9, 0], line, |&n| n == 1), Some((1, 1)));
+}
+
+#[test]
+fn gives_up_when_no_goal_is_reachable() {
+    assert_eq!(bfs([0], line, |&n| n == 20), None);
+}
+
+#[test]
+fn measures_the_distance_to_every_reachable_state() {
+    let distances = bfs_distances(3, line);
+    assert_eq!(distances.len(), 10);
+    assert_eq!(distances[&3], 0);
+    assert_eq!(distances[&0], 3);
+    assert_eq!(distances[&9], 6);
+    assert!(!distances.contains_key(&20));
+    assert_eq!(bfs_distances(20, line).len(), 2);
+}
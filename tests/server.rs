@@ -0,0 +1,106 @@
+#![cfg(feature = "server")]
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::sync::Arc;
+use std::thread;
+
+use libaoc::ledger::Ledger;
+use libaoc::runner::{Answer, RunOptions};
+use libaoc::server::Server;
+use serde_json::Value;
+
+struct Running {
+    server: Arc<Server>,
+    addr: SocketAddr,
+    thread: Option<thread::JoinHandle<()>>,
+}
+
+impl Drop for Running {
+    fn drop(&mut self) {
+        self.server.shutdown();
+        if let Some(thread) = self.thread.take() {
+            thread.join().unwrap();
+        }
+    }
+}
+
+fn start() -> Running {
+    let server = Arc::new(Server::bind("127.0.0.1:0", RunOptions::default()).unwrap());
+    let addr = server.addr().unwrap();
+    let thread = thread::spawn({
+        let server = server.clone();
+        move || server.run()
+    });
+    Running { server, addr, thread: Some(thread) }
+}
+
+fn request(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, Value) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(stream, "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}", method, path, body.len(), body).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
+
+#[test]
+fn health() {
+    let running = start();
+    let (status, body) = request(running.addr, "GET", "/health", "");
+    assert_eq!(status, 200);
+    assert_eq!(body["status"], "ok");
+}
+
+#[test]
+fn lists_the_registry() {
+    let running = start();
+    let (status, body) = request(running.addr, "GET", "/days", "");
+    assert_eq!(status, 200);
+    let days = body.as_array().unwrap();
+    assert_eq!(days.len(), 25);
+    assert_eq!(days[15]["day"], 16);
+    assert_eq!(days[15]["repl"], true);
+}
+
+#[test]
+fn solves_the_examples() {
+    let running = start();
+    for (day, part) in [(1, 1), (1, 2), (5, 1), (12, 2)] {
+        let input = std::fs::read_to_string(format!("data/2022/day{:02}.example", day)).unwrap();
+        let expected = Ledger::load(2022, day).unwrap().get("example", part).unwrap().clone();
+        let (status, body) = request(running.addr, "POST", &format!("/solve/{}/{}?year=2022", day, part), &input);
+        assert_eq!(status, 200);
+        assert_eq!(body["status"], "ok");
+        assert_eq!(serde_json::from_value::<Answer>(body["answer"].clone()).unwrap(), expected);
+        assert!(body["time_ms"].as_f64().unwrap() >= 0.0);
+    }
+}
+
+#[test]
+fn reports_failing_solvers() {
+    let running = start();
+    let (status, body) = request(running.addr, "POST", "/solve/5/1", "not a drawing");
+    assert_eq!(status, 200);
+    assert_eq!(body["status"], "panic");
+}
+
+#[test]
+fn rejects_bad_requests() {
+    let running = start();
+    assert_eq!(request(running.addr, "POST", "/solve/26/1", "").0, 404);
+    assert_eq!(request(running.addr, "POST", "/solve/1/3", "").0, 404);
+    assert_eq!(request(running.addr, "POST", "/solve/one/1", "").0, 400);
+    assert_eq!(request(running.addr, "GET", "/solve/1/1", "").0, 405);
+    assert_eq!(request(running.addr, "GET", "/nowhere", "").0, 404);
+}
+
+#[test]
+fn rejects_inputs_over_the_limit() {
+    let running = start();
+    let input = "1\n".repeat((16 << 20) / 2 + 1);
+    let (status, body) = request(running.addr, "POST", "/solve/1/1", &input);
+    assert_eq!(status, 413);
+    assert!(body["error"].as_str().unwrap().contains("larger than"));
+}
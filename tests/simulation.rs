@@ -0,0 +1,110 @@
+use std::sync::atomic::Ordering::Relaxed;
+
+use libaoc::context::{self, Context};
+use libaoc::progress::{Progress, ProgressOptions, Stopped};
+use libaoc::simulation::{Cycle, Driver, Outcome, Simulation, Stop};
+
+// follows next from node to node, a node that leads to itself is a fixed point
+struct Walk {
+    next: Vec<usize>,
+    at: usize,
+}
+
+impl Simulation for Walk {
+    type Key = usize;
+
+    fn step(&mut self) -> bool {
+        let next = self.next[self.at];
+        let changed = next != self.at;
+        self.at = next;
+        changed
+    }
+
+    fn key(&self) -> Option<usize> {
+        Some(self.at)
+    }
+
+    fn render(&self) -> String {
+        self.at.to_string()
+    }
+}
+
+fn walk(next: &[usize]) -> Walk {
+    Walk { next: next.to_vec(), at: 0 }
+}
+
+#[test]
+fn stops_after_the_total_number_of_steps() {
+    let mut driver = Driver::new(walk(&[1, 2, 3, 0]));
+    assert_eq!(driver.run(Stop::Steps(5)).unwrap(), Outcome::Steps);
+    assert_eq!((driver.steps(), driver.simulation.at), (5, 1));
+    assert_eq!(driver.run(Stop::Steps(7)).unwrap(), Outcome::Steps);
+    assert_eq!((driver.steps(), driver.simulation.at), (7, 3));
+}
+
+#[test]
+fn stops_at_a_fixed_point() {
+    let mut driver = Driver::new(walk(&[1, 2, 2]));
+    assert_eq!(driver.run(Stop::Steps(100)).unwrap(), Outcome::FixedPoint);
+    // the step that changed nothing is counted
+    assert_eq!((driver.steps(), driver.simulation.at), (3, 2));
+}
+
+#[test]
+fn stops_when_the_predicate_holds() {
+    let mut driver = Driver::new(walk(&[1, 2, 3, 0]));
+    assert_eq!(driver.run(Stop::When(&|walk: &Walk| walk.at == 3)).unwrap(), Outcome::Predicate);
+    assert_eq!(driver.steps(), 3);
+    // a predicate that already holds stops before any step
+    assert_eq!(driver.run(Stop::When(&|walk: &Walk| walk.at == 3)).unwrap(), Outcome::Predicate);
+    assert_eq!(driver.steps(), 3);
+}
+
+#[test]
+fn records_the_initial_state_and_every_step() {
+    let mut driver = Driver::new(walk(&[1, 2, 3, 0])).with_history();
+    driver.run(Stop::Steps(5)).unwrap();
+    assert_eq!(driver.history(), ["0", "1", "2", "3", "0", "1"]);
+    let mut driver = Driver::new(walk(&[1, 2, 3, 0]));
+    driver.run(Stop::Steps(5)).unwrap();
+    assert!(driver.history().is_empty());
+}
+
+#[test]
+fn finds_a_cycle_back_to_the_initial_state() {
+    let mut driver = Driver::new(walk(&[1, 2, 3, 0])).detect_cycles();
+    assert_eq!(driver.run(Stop::Steps(100)).unwrap(), Outcome::Cycle(Cycle { start: 0, length: 4 }));
+    assert_eq!(driver.steps(), 4);
+}
+
+#[test]
+fn finds_a_cycle_after_a_lead_in() {
+    let mut driver = Driver::new(walk(&[1, 2, 3, 4, 2])).detect_cycles();
+    assert_eq!(driver.run(Stop::Steps(100)).unwrap(), Outcome::Cycle(Cycle { start: 2, length: 3 }));
+    assert_eq!((driver.steps(), driver.simulation.at), (5, 2));
+}
+
+#[test]
+fn stops_detecting_cycles_after_skipping() {
+    let mut driver = Driver::new(walk(&[1, 2, 3, 0])).detect_cycles();
+    let Outcome::Cycle(cycle) = driver.run(Stop::Steps(100)).unwrap() else { panic!("no cycle") };
+    driver.skip(20 * cycle.length);
+    assert_eq!(driver.run(Stop::Steps(90)).unwrap(), Outcome::Steps);
+    assert_eq!((driver.steps(), driver.simulation.at), (90, 2));
+}
+
+#[test]
+fn stops_at_the_next_step_once_cancelled() {
+    let options = ProgressOptions::default();
+    let context = Context { progress: Some(Progress::start(&options, "walk".to_string())), ..Context::default() };
+    let mut driver = Driver::new(walk(&[1, 2, 3, 0]));
+    let cancel = |walk: &Walk| {
+        if walk.at == 3 {
+            options.cancel.store(true, Relaxed);
+        }
+        false
+    };
+    let stopped = context::scope(context, || driver.run(Stop::When(&cancel))).unwrap_err();
+    assert_eq!(stopped.downcast_ref::<Stopped>(), Some(&Stopped::Cancelled));
+    assert_eq!(driver.steps(), 3);
+}